@@ -0,0 +1,36 @@
+//! Uses srestricted with array backings only, this crate doesn't link `alloc`.
+
+#![no_std]
+
+use srestricted::{ExactSized, InlineVec, NonEmpty, SizeRestricted};
+
+/// A restricted collection without an allocator
+pub type Samples = SizeRestricted<u16, InlineVec<u16, 32>, 4, 32>;
+
+/// Push a sample and return the average of the samples
+pub fn average(samples: &mut Samples, sample: u16) -> u16 {
+    if samples.push(sample).is_err() {
+        samples.swap_remove(0);
+        let _ = samples.push(sample);
+    }
+
+    let sum = samples.fold(0u32, |acc, &val| acc + u32::from(val));
+    (sum / samples.len() as u32) as u16
+}
+
+/// Retain the non-zero samples and sort them
+pub fn retain_sorted(samples: &mut Samples) -> bool {
+    let retained = samples.retain(|&val| val != 0).is_ok();
+    samples.sort_unstable();
+    retained
+}
+
+/// Get the largest element of a collection which can't be empty
+pub fn largest(samples: &NonEmpty<u16, InlineVec<u16, 8>>) -> u16 {
+    *samples.max_nonempty()
+}
+
+/// Create a block of exactly 4 zeroed samples
+pub fn zeroed() -> ExactSized<u16, InlineVec<u16, 4>, 4> {
+    ExactSized::default()
+}
@@ -0,0 +1,137 @@
+//! A builder to incrementally construct a [`SizeRestricted`] collection
+
+use core::marker::PhantomData;
+
+use crate::{LinearSizedCollection, SizeRangeError, SizeRestricted};
+
+/// A builder for a [`SizeRestricted`] collection.
+///
+/// The collection inside the builder may be smaller than `MIN` while it is under construction, only `MAX` is enforced.
+/// `MIN` is checked when the [`SizeRestricted`] collection is created with [`build`](SizeRestrictedBuilder::build).
+#[derive(Debug, Clone)]
+pub struct SizeRestrictedBuilder<T, C: LinearSizedCollection<T>, const MIN: usize, const MAX: usize>
+{
+    /// The collection under construction
+    collection: C,
+    /// Marks the element type `T`, which is only used in the bounds of `C`
+    _phantom: PhantomData<T>,
+}
+
+impl<T, C: LinearSizedCollection<T>, const MIN: usize, const MAX: usize>
+    SizeRestrictedBuilder<T, C, MIN, MAX>
+{
+    /// Create a new builder starting with an empty collection
+    pub fn new() -> Self
+    where
+        C: Default,
+    {
+        Self {
+            collection: C::default(),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Get the current length of the collection under construction
+    pub fn len(&self) -> usize {
+        self.collection.len()
+    }
+
+    /// Check wether the collection under construction is empty
+    pub fn is_empty(&self) -> bool {
+        self.collection.is_empty()
+    }
+
+    /// Push an element to the collection under construction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SizeRangeError::TooLarge`] and `val` if the collection already has `MAX` elements and pushing `val`
+    /// would grow it, see [`LinearSizedCollection::grows_on_push`].
+    #[must_use = "the value is returned on error and lost if the result is ignored"]
+    pub fn push(&mut self, val: T) -> Result<(), (SizeRangeError, T)> {
+        if self.collection.len() >= MAX && self.collection.grows_on_push(&val) {
+            Err((SizeRangeError::TooLarge, val))
+        } else {
+            self.collection.push(val);
+            Ok(())
+        }
+    }
+
+    /// Push all elements of `iter` to the collection under construction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SizeRangeError::TooLarge`] as soon as an element doesn't fit anymore, this element and
+    /// all remaining elements of `iter` are dropped. All elements before it are kept.
+    #[must_use = "the remaining elements are dropped if this returns an error"]
+    pub fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) -> Result<(), SizeRangeError> {
+        for val in iter {
+            self.push(val).map_err(|(e, _)| e)?;
+        }
+
+        Ok(())
+    }
+
+    /// Finish the construction and create the [`SizeRestricted`] collection.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SizeRangeError::TooSmall`] and the collection if it has less than `MIN` elements.
+    pub fn build(self) -> Result<SizeRestricted<T, C, MIN, MAX>, (SizeRangeError, C)> {
+        SizeRestricted::new(self.collection)
+    }
+}
+
+impl<T, C: LinearSizedCollection<T> + Default, const MIN: usize, const MAX: usize> Default
+    for SizeRestrictedBuilder<T, C, MIN, MAX>
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod test {
+    use alloc::vec::Vec;
+
+    use crate::{NonEmpty, SizeRangeError, SizeRestricted};
+
+    #[test]
+    fn build_nonempty() {
+        let mut builder = NonEmpty::<i32, Vec<_>>::builder();
+        builder.push(1).unwrap();
+        builder.extend([2, 3]).unwrap();
+
+        let collection = builder.build().unwrap();
+        assert_eq!(collection.inner(), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn build_empty_errors() {
+        let builder = NonEmpty::<i32, Vec<_>>::builder();
+        let (e, collection) = builder.build().unwrap_err();
+        assert_eq!(e, SizeRangeError::TooSmall);
+        assert!(collection.is_empty());
+    }
+
+    #[test]
+    fn push_enforces_max() {
+        let mut builder = SizeRestricted::<i32, Vec<_>, 1, 2>::builder();
+        assert_eq!(builder.extend([1, 2, 3]), Err(SizeRangeError::TooLarge));
+        assert_eq!(builder.push(4), Err((SizeRangeError::TooLarge, 4)));
+        assert_eq!(builder.len(), 2);
+        assert_eq!(builder.build().unwrap().inner(), &[1, 2]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn push_duplicate_at_max() {
+        use std::collections::HashSet;
+
+        let mut builder = SizeRestricted::<i32, HashSet<_>, 1, 2>::builder();
+        builder.extend([1, 2]).unwrap();
+        assert_eq!(builder.push(1), Ok(()));
+        assert_eq!(builder.push(3), Err((SizeRangeError::TooLarge, 3)));
+        assert_eq!(builder.len(), 2);
+    }
+}
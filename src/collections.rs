@@ -1,6 +1,282 @@
 //! Implementation of [`LinearSizedCollection`] for various types
 
+use crate::LinearSizedCollection;
+
+#[cfg(feature = "alloc")]
 mod alloc_collections;
+mod array_collections;
+#[cfg(feature = "gap_buffer")]
+mod gap;
+#[cfg(feature = "impl_generic_array")]
+mod generic_array_collections;
+#[cfg(feature = "impl_im")]
+mod im_collections;
+#[cfg(feature = "impl_indexmap")]
+mod indexmap_collections;
+#[cfg(feature = "std")]
+mod std_collections;
+#[cfg(feature = "impl_tinyvec")]
+mod tinyvec_collections;
 
 #[cfg(feature = "alloc")]
 pub use alloc_collections::*;
+pub use array_collections::*;
+#[cfg(feature = "gap_buffer")]
+pub use gap::*;
+
+/// Pop up to `count` elements from the back of `collection` and call `f` with the collection and every popped element
+/// in order from the front to the back, `f` decides which elements are pushed back.
+///
+/// The popped elements are buffered in a [`Vec`](alloc::vec::Vec).
+#[cfg(feature = "alloc")]
+pub(crate) fn pop_and_replay<T, C, F>(collection: &mut C, count: usize, mut f: F)
+where
+    C: LinearSizedCollection<T> + ?Sized,
+    F: FnMut(&mut C, T),
+{
+    let mut scratch = alloc::vec::Vec::with_capacity(count.min(collection.len()));
+    for _ in 0..count {
+        match collection.pop() {
+            Some(val) => scratch.push(val),
+            None => break,
+        }
+    }
+
+    while let Some(val) = scratch.pop() {
+        f(collection, val);
+    }
+}
+
+/// Pop up to `count` elements from the back of `collection` and call `f` with the collection and every popped element
+/// in order from the front to the back, `f` decides which elements are pushed back.
+///
+/// Without `alloc` the popped elements are buffered on the stack by recursing once per element.
+#[cfg(not(feature = "alloc"))]
+pub(crate) fn pop_and_replay<T, C, F>(collection: &mut C, count: usize, mut f: F)
+where
+    C: LinearSizedCollection<T> + ?Sized,
+    F: FnMut(&mut C, T),
+{
+    /// Pop one element, replay the remaining ones and then the popped element
+    fn replay<T, C, F>(collection: &mut C, count: usize, f: &mut F)
+    where
+        C: LinearSizedCollection<T> + ?Sized,
+        F: FnMut(&mut C, T),
+    {
+        if count == 0 {
+            return;
+        }
+
+        if let Some(val) = collection.pop() {
+            replay(collection, count - 1, f);
+            f(collection, val);
+        }
+    }
+
+    replay(collection, count, &mut f);
+}
+
+/// Retain the elements of `collection` for which `f` returns true if at least `min` elements are retained, otherwise
+/// the collection is not changed. `f` is called once for every element in order from the front to the back, returns
+/// wether the elements were retained.
+///
+/// The results of `f` are buffered in a [`Vec`](alloc::vec::Vec) and applied with
+/// [`retain`](LinearSizedCollection::retain).
+#[cfg(feature = "alloc")]
+pub(crate) fn retain_at_least<T, C, F>(collection: &mut C, min: usize, mut f: F) -> bool
+where
+    C: LinearSizedCollection<T>,
+    F: FnMut(&T) -> bool,
+{
+    let mut decisions = alloc::vec::Vec::with_capacity(collection.len());
+    let retained = collection.count_retained(|val| {
+        let keep = f(val);
+        decisions.push(keep);
+        keep
+    });
+    if retained < min {
+        return false;
+    }
+
+    let mut decisions = decisions.into_iter();
+    collection.retain(|_| decisions.next().unwrap_or(true));
+    true
+}
+
+/// Retain the elements of `collection` for which `f` returns true if at least `min` elements are retained, otherwise
+/// the collection is not changed. `f` is called once for every element in order from the front to the back, returns
+/// wether the elements were retained.
+///
+/// Without `alloc` all elements are popped and buffered on the stack by recursing once per element, then the
+/// retained elements are pushed back.
+#[cfg(not(feature = "alloc"))]
+pub(crate) fn retain_at_least<T, C, F>(collection: &mut C, min: usize, mut f: F) -> bool
+where
+    C: LinearSizedCollection<T>,
+    F: FnMut(&T) -> bool,
+{
+    use core::cell::Cell;
+
+    /// A popped element, linked to the element which was popped before it and is behind it in the collection
+    struct Popped<'a, T> {
+        /// The element, taken when it is pushed back
+        val: Cell<Option<T>>,
+        /// Wether the element is retained
+        keep: Cell<bool>,
+        /// The element behind this one
+        behind: Option<&'a Popped<'a, T>>,
+    }
+
+    /// Pop one element and recurse, once the collection is empty `front` is the front element
+    fn descend<T, C, F>(
+        collection: &mut C,
+        front: Option<&Popped<'_, T>>,
+        min: usize,
+        f: &mut F,
+    ) -> bool
+    where
+        C: LinearSizedCollection<T>,
+        F: FnMut(&T) -> bool,
+    {
+        if let Some(val) = collection.pop() {
+            let popped = Popped {
+                val: Cell::new(Some(val)),
+                keep: Cell::new(true),
+                behind: front,
+            };
+            return descend(collection, Some(&popped), min, f);
+        }
+
+        let mut retained = 0;
+        let mut next = front;
+        while let Some(popped) = next {
+            let val = popped.val.take();
+            let keep = val.as_ref().is_some_and(&mut *f);
+            popped.val.set(val);
+            popped.keep.set(keep);
+            retained += usize::from(keep);
+            next = popped.behind;
+        }
+
+        let fits = retained >= min;
+        let mut next = front;
+        while let Some(popped) = next {
+            if let Some(val) = popped.val.take().filter(|_| !fits || popped.keep.get()) {
+                collection.push(val);
+            }
+            next = popped.behind;
+        }
+        fits
+    }
+
+    descend(collection, None, min, &mut f)
+}
+
+/// Retain the elements of `slice` for which `f` returns true in place, `f` is called for every element in order.
+/// The retained elements are moved to the front in order and their number is returned, the remaining elements are
+/// the removed ones.
+pub(crate) fn retain_mut_in_place<T, F: FnMut(&mut T) -> bool>(slice: &mut [T], mut f: F) -> usize {
+    let mut retained = 0;
+    for i in 0..slice.len() {
+        if f(&mut slice[i]) {
+            slice.swap(retained, i);
+            retained += 1;
+        }
+    }
+
+    retained
+}
+
+/// Mark which elements of `slice` remain after removing every element for which `same` returns true, `same` is
+/// called with the last remaining element and the following element.
+#[cfg(feature = "alloc")]
+pub(crate) fn mark_deduped_in_slice<T, F: FnMut(&T, &T) -> bool>(
+    slice: &[T],
+    mut same: F,
+) -> alloc::vec::Vec<bool> {
+    let mut remaining = alloc::vec::Vec::with_capacity(slice.len());
+    let Some(mut last) = slice.first() else {
+        return remaining;
+    };
+
+    remaining.push(true);
+    for val in &slice[1..] {
+        let remains = !same(last, val);
+        if remains {
+            last = val;
+        }
+        remaining.push(remains);
+    }
+
+    remaining
+}
+
+#[cfg(test)]
+mod default_test {
+    use crate::LinearSizedCollection;
+
+    /// A collection on an array which only implements the required methods of [`LinearSizedCollection`] to test the
+    /// default implementations without `alloc`
+    pub struct ArrayStack {
+        /// The elements, only the first `len` are used
+        elements: [i32; 128],
+        /// The number of used elements
+        len: usize,
+    }
+
+    impl Default for ArrayStack {
+        fn default() -> Self {
+            Self {
+                elements: [0; 128],
+                len: 0,
+            }
+        }
+    }
+
+    impl LinearSizedCollection<i32> for ArrayStack {
+        fn len(&self) -> usize {
+            self.len
+        }
+
+        fn push(&mut self, val: i32) {
+            self.elements[self.len] = val;
+            self.len += 1;
+        }
+
+        fn pop(&mut self) -> Option<i32> {
+            self.len = self.len.checked_sub(1)?;
+            Some(self.elements[self.len])
+        }
+
+        fn reserve(&mut self, _additional: usize) {}
+    }
+
+    crate::test::linear_collection_test!(
+        crate::collections::default_test::ArrayStack::default(),
+        array_stack_test
+    );
+
+    #[test]
+    fn retain_at_least() {
+        let mut stack = ArrayStack::default();
+        for val in 0..6 {
+            stack.push(val);
+        }
+
+        let mut calls = 0;
+        let retained = crate::collections::retain_at_least(&mut stack, 4, |val| {
+            calls += 1;
+            val % 2 == 0
+        });
+        assert!(!retained);
+        assert_eq!(calls, 6);
+        assert_eq!(stack.elements[..stack.len], [0, 1, 2, 3, 4, 5]);
+
+        assert!(crate::collections::retain_at_least(
+            &mut stack,
+            3,
+            |val| val % 2 == 0
+        ));
+        assert_eq!(stack.elements[..stack.len], [0, 2, 4]);
+    }
+}
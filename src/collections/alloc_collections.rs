@@ -1,9 +1,12 @@
 //! Implementations of [`LinearSizedCollection`] for alloc types
 
 use alloc::{collections, string::String, vec::Vec};
-use core::ops::DerefMut;
+use core::{cmp::Ordering, ops::DerefMut, str::FromStr};
 
-use crate::{LinearSizedCollection, ViewMut};
+use crate::{
+    AsSlices, BinarySearch, Iterable, IterableMut, LinearSizedCollection, LinearSizedDeque,
+    ReserveError, SizeRangeError, SizeRestricted, ViewMut,
+};
 
 impl<T> LinearSizedCollection<T> for alloc::vec::Vec<T> {
     fn len(&self) -> usize {
@@ -25,6 +28,54 @@ impl<T> LinearSizedCollection<T> for alloc::vec::Vec<T> {
     fn reserve(&mut self, additional: usize) {
         self.reserve(additional);
     }
+
+    fn reserve_exact(&mut self, additional: usize) {
+        self.reserve_exact(additional);
+    }
+
+    fn try_reserve(&mut self, additional: usize) -> Result<(), ReserveError> {
+        self.try_reserve(additional).map_err(|_| ReserveError)
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.shrink_to_fit();
+    }
+
+    unsafe fn set_len(&mut self, len: usize) {
+        // SAFETY: Guaranteed by the caller
+        unsafe { self.set_len(len) }
+    }
+
+    fn swap_remove(&mut self, index: usize) -> Option<T> {
+        (index < self.len()).then(|| self.swap_remove(index))
+    }
+
+    fn count_retained<F: FnMut(&T) -> bool>(&mut self, mut f: F) -> usize {
+        self.as_slice().iter().filter(|val| f(val)).count()
+    }
+
+    fn retain<F: FnMut(&T) -> bool>(&mut self, f: F) {
+        self.retain(f);
+    }
+
+    fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, f: F) {
+        self.retain_mut(f);
+    }
+
+    fn mark_deduped<F: FnMut(&T, &T) -> bool>(&mut self, same: F) -> Vec<bool> {
+        super::mark_deduped_in_slice(self, same)
+    }
+
+    fn extract_if<F: FnMut(&mut T) -> bool>(&mut self, f: F) -> Vec<T> {
+        self.extract_if(.., f).collect()
+    }
+
+    fn extend_from_slice(&mut self, other: &[T])
+    where
+        T: Clone,
+    {
+        self.extend_from_slice(other);
+    }
 }
 
 // SAFETY: A slice can't mutate its containers length
@@ -35,6 +86,111 @@ unsafe impl<'a, T: 'a> ViewMut<'a> for Vec<T> {
     }
 }
 
+impl<'a, T: 'a> Iterable<'a, T> for Vec<T> {
+    type Iter = core::slice::Iter<'a, T>;
+    fn iter(&'a self) -> Self::Iter {
+        self.as_slice().iter()
+    }
+}
+
+// SAFETY: Iterating over mutable references can't mutate the length
+unsafe impl<'a, T: 'a> IterableMut<'a, T> for Vec<T> {
+    type IterMut = core::slice::IterMut<'a, T>;
+    fn iter_mut(&'a mut self) -> Self::IterMut {
+        self.as_mut_slice().iter_mut()
+    }
+}
+
+impl<T> BinarySearch<T> for Vec<T> {
+    fn binary_search_by<F: FnMut(&T) -> Ordering>(&self, f: F) -> Result<usize, usize> {
+        self.as_slice().binary_search_by(f)
+    }
+
+    fn partition_point<F: FnMut(&T) -> bool>(&self, pred: F) -> usize {
+        self.as_slice().partition_point(pred)
+    }
+}
+
+/// A boxed slice always has the same length, so [`push`](LinearSizedCollection::push) and
+/// [`pop`](LinearSizedCollection::pop) panic. Every operation which changes the length panics as well.
+///
+/// Only an [`ExactSized`](crate::ExactSized) collection with the length of the boxed slice is meaningful,
+/// as it never has to change the length.
+impl<T> LinearSizedCollection<T> for alloc::boxed::Box<[T]> {
+    fn len(&self) -> usize {
+        <[T]>::len(self)
+    }
+
+    fn push(&mut self, _val: T) {
+        panic!("Can't push to a boxed slice as its length is fixed")
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        panic!("Can't pop from a boxed slice as its length is fixed")
+    }
+
+    fn reserve(&mut self, _additional: usize) {}
+
+    fn count_retained<F: FnMut(&T) -> bool>(&mut self, mut f: F) -> usize {
+        <[T]>::iter(self).filter(|val| f(val)).count()
+    }
+
+    fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, mut f: F) {
+        for val in <[T]>::iter_mut(self) {
+            assert!(
+                f(val),
+                "Can't remove from a boxed slice as its length is fixed"
+            );
+        }
+    }
+
+    fn extract_if<F: FnMut(&mut T) -> bool>(&mut self, mut f: F) -> Vec<T> {
+        for val in <[T]>::iter_mut(self) {
+            assert!(
+                !f(val),
+                "Can't remove from a boxed slice as its length is fixed"
+            );
+        }
+
+        Vec::new()
+    }
+
+    fn mark_deduped<F: FnMut(&T, &T) -> bool>(&mut self, same: F) -> Vec<bool> {
+        super::mark_deduped_in_slice(self, same)
+    }
+
+    fn swap_remove(&mut self, index: usize) -> Option<T> {
+        if index >= <[T]>::len(self) {
+            return None;
+        }
+
+        panic!("Can't remove from a boxed slice as its length is fixed")
+    }
+}
+
+// SAFETY: A slice can't mutate its containers length
+unsafe impl<'a, T: 'a> ViewMut<'a> for alloc::boxed::Box<[T]> {
+    type MutableView = &'a mut [T];
+    fn view_mut(&'a mut self) -> Self::MutableView {
+        self
+    }
+}
+
+impl<'a, T: 'a> Iterable<'a, T> for alloc::boxed::Box<[T]> {
+    type Iter = core::slice::Iter<'a, T>;
+    fn iter(&'a self) -> Self::Iter {
+        <[T]>::iter(self)
+    }
+}
+
+// SAFETY: Iterating over mutable references can't mutate the length
+unsafe impl<'a, T: 'a> IterableMut<'a, T> for alloc::boxed::Box<[T]> {
+    type IterMut = core::slice::IterMut<'a, T>;
+    fn iter_mut(&'a mut self) -> Self::IterMut {
+        <[T]>::iter_mut(self)
+    }
+}
+
 impl<T> LinearSizedCollection<T> for collections::VecDeque<T> {
     fn len(&self) -> usize {
         self.len()
@@ -55,6 +211,36 @@ impl<T> LinearSizedCollection<T> for collections::VecDeque<T> {
     fn reserve(&mut self, additional: usize) {
         self.reserve(additional);
     }
+
+    fn reserve_exact(&mut self, additional: usize) {
+        self.reserve_exact(additional);
+    }
+
+    fn try_reserve(&mut self, additional: usize) -> Result<(), ReserveError> {
+        self.try_reserve(additional).map_err(|_| ReserveError)
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.shrink_to_fit();
+    }
+
+    fn swap_remove(&mut self, index: usize) -> Option<T> {
+        self.swap_remove_back(index)
+    }
+
+    fn count_retained<F: FnMut(&T) -> bool>(&mut self, mut f: F) -> usize {
+        collections::VecDeque::iter(self)
+            .filter(|val| f(val))
+            .count()
+    }
+
+    fn retain<F: FnMut(&T) -> bool>(&mut self, f: F) {
+        self.retain(f);
+    }
+
+    fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, f: F) {
+        self.retain_mut(f);
+    }
 }
 
 // SAFETY: A slice can't mutate its containers length
@@ -65,6 +251,134 @@ unsafe impl<'a, T: 'a> ViewMut<'a> for collections::VecDeque<T> {
     }
 }
 
+impl<T> AsSlices<T> for Vec<T> {
+    fn as_slices(&self) -> (&[T], &[T]) {
+        (self.as_slice(), &[])
+    }
+}
+
+impl<T> AsSlices<T> for collections::VecDeque<T> {
+    fn as_slices(&self) -> (&[T], &[T]) {
+        self.as_slices()
+    }
+}
+
+impl<T> LinearSizedDeque<T> for collections::VecDeque<T> {
+    fn push_front(&mut self, val: T) {
+        self.push_front(val);
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        self.pop_front()
+    }
+
+    fn truncate_front(&mut self, len: usize) {
+        let remove = self.len().saturating_sub(len);
+        self.drain(..remove);
+    }
+}
+
+impl<'a, T: 'a> Iterable<'a, T> for collections::VecDeque<T> {
+    type Iter = collections::vec_deque::Iter<'a, T>;
+    fn iter(&'a self) -> Self::Iter {
+        collections::VecDeque::iter(self)
+    }
+}
+
+// SAFETY: Iterating over mutable references can't mutate the length
+unsafe impl<'a, T: 'a> IterableMut<'a, T> for collections::VecDeque<T> {
+    type IterMut = collections::vec_deque::IterMut<'a, T>;
+    fn iter_mut(&'a mut self) -> Self::IterMut {
+        collections::VecDeque::iter_mut(self)
+    }
+}
+
+impl<T> BinarySearch<T> for collections::VecDeque<T> {
+    fn binary_search_by<F: FnMut(&T) -> Ordering>(&self, f: F) -> Result<usize, usize> {
+        self.binary_search_by(f)
+    }
+
+    fn partition_point<F: FnMut(&T) -> bool>(&self, pred: F) -> usize {
+        self.partition_point(pred)
+    }
+}
+
+/// A [`VecDeque`](collections::VecDeque) which is used from the front, [`push`](LinearSizedCollection::push) and
+/// [`pop`](LinearSizedCollection::pop) operate on the front instead of the back.
+///
+/// Iterating goes from the least recently to the most recently pushed element, which is the reverse order of the inner
+/// deque. The elements can't be viewed as slice in this order, so there is no [`ViewMut`] and the index based methods of
+/// [`SizeRestricted`] aren't available:
+///
+/// ```compile_fail
+/// # use std::collections::VecDeque;
+/// # use srestricted::{FrontDeque, SizeRestricted};
+/// let mut collection = SizeRestricted::<i32, _, 0, 8>::new(FrontDeque(VecDeque::from([1, 2]))).unwrap();
+/// collection.get_or_extend(5, || 0);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FrontDeque<T>(pub collections::VecDeque<T>);
+
+impl<T> LinearSizedCollection<T> for FrontDeque<T> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn push(&mut self, val: T) {
+        self.0.push_front(val);
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        self.0.pop_front()
+    }
+
+    fn shrink_to(&mut self, len: usize) {
+        let remove = self.0.len().saturating_sub(len);
+        self.0.drain(..remove);
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional);
+    }
+
+    fn reserve_exact(&mut self, additional: usize) {
+        self.0.reserve_exact(additional);
+    }
+
+    fn try_reserve(&mut self, additional: usize) -> Result<(), ReserveError> {
+        self.0.try_reserve(additional).map_err(|_| ReserveError)
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit();
+    }
+
+    fn count_retained<F: FnMut(&T) -> bool>(&mut self, mut f: F) -> usize {
+        self.0.iter().rev().filter(|val| f(val)).count()
+    }
+}
+
+impl<'a, T: 'a> Iterable<'a, T> for FrontDeque<T> {
+    type Iter = core::iter::Rev<collections::vec_deque::Iter<'a, T>>;
+    fn iter(&'a self) -> Self::Iter {
+        self.0.iter().rev()
+    }
+}
+
+// SAFETY: Iterating over mutable references can't mutate the length
+unsafe impl<'a, T: 'a> IterableMut<'a, T> for FrontDeque<T> {
+    type IterMut = core::iter::Rev<collections::vec_deque::IterMut<'a, T>>;
+    fn iter_mut(&'a mut self) -> Self::IterMut {
+        self.0.iter_mut().rev()
+    }
+}
+
+impl<T> From<collections::VecDeque<T>> for FrontDeque<T> {
+    fn from(deque: collections::VecDeque<T>) -> Self {
+        Self(deque)
+    }
+}
+
 impl<T> LinearSizedCollection<T> for collections::LinkedList<T> {
     fn len(&self) -> usize {
         self.len()
@@ -79,13 +393,212 @@ impl<T> LinearSizedCollection<T> for collections::LinkedList<T> {
     }
 
     fn reserve(&mut self, _additional: usize) {}
+
+    fn swap_remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.len() {
+            return None;
+        }
+
+        let mut tail = self.split_off(index);
+        let removed = tail.pop_front();
+        if let Some(last) = tail.pop_back() {
+            self.push_back(last);
+        }
+        self.append(&mut tail);
+        removed
+    }
+
+    fn count_retained<F: FnMut(&T) -> bool>(&mut self, mut f: F) -> usize {
+        collections::LinkedList::iter(self)
+            .filter(|val| f(val))
+            .count()
+    }
+
+    fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, mut f: F) {
+        let mut retained = collections::LinkedList::new();
+        while let Some(mut val) = self.pop_front() {
+            if f(&mut val) {
+                retained.push_back(val);
+            }
+        }
+        *self = retained;
+    }
 }
 
-impl LinearSizedCollection<char> for String {
+impl<T> LinearSizedDeque<T> for collections::LinkedList<T> {
+    fn push_front(&mut self, val: T) {
+        self.push_front(val);
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        self.pop_front()
+    }
+}
+
+impl<'a, T: 'a> Iterable<'a, T> for collections::LinkedList<T> {
+    type Iter = collections::linked_list::Iter<'a, T>;
+    fn iter(&'a self) -> Self::Iter {
+        collections::LinkedList::iter(self)
+    }
+}
+
+// SAFETY: Iterating over mutable references can't mutate the length
+unsafe impl<'a, T: 'a> IterableMut<'a, T> for collections::LinkedList<T> {
+    type IterMut = collections::linked_list::IterMut<'a, T>;
+    fn iter_mut(&'a mut self) -> Self::IterMut {
+        collections::LinkedList::iter_mut(self)
+    }
+}
+
+/// A [`LinkedList`](collections::LinkedList) has no random access so this is a linear search which is O(n).
+impl<T> BinarySearch<T> for collections::LinkedList<T> {
+    fn binary_search_by<F: FnMut(&T) -> Ordering>(&self, mut f: F) -> Result<usize, usize> {
+        for (i, elem) in self.iter().enumerate() {
+            match f(elem) {
+                Ordering::Less => {}
+                Ordering::Equal => return Ok(i),
+                Ordering::Greater => return Err(i),
+            }
+        }
+
+        Err(self.len())
+    }
+
+    fn partition_point<F: FnMut(&T) -> bool>(&self, mut pred: F) -> usize {
+        self.iter().take_while(|elem| pred(elem)).count()
+    }
+}
+
+/// A mutable view into a [`LinkedList`](collections::LinkedList) which only allows mutating its elements.
+///
+/// Elements can't be inserted or removed through this view so the length of the list stays the same.
+#[derive(Debug)]
+pub struct LinkedListViewMut<'a, T> {
+    /// The list this is a view into
+    list: &'a mut collections::LinkedList<T>,
+}
+
+impl<'a, T> LinkedListViewMut<'a, T> {
+    /// Get the length of the viewed list
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    /// Check wether the viewed list is empty
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// Get a mutable reference to the first element
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.list.front_mut()
+    }
+
+    /// Get a mutable reference to the last element
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.list.back_mut()
+    }
+
+    /// Get a mutable reference to the element at `index`. This is O(n) as the list has to be traversed.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.list.iter_mut().nth(index)
+    }
+
+    /// Iterate over immutable references to the elements
+    pub fn iter(&self) -> collections::linked_list::Iter<'_, T> {
+        self.list.iter()
+    }
+
+    /// Iterate over mutable references to the elements
+    pub fn iter_mut(&mut self) -> collections::linked_list::IterMut<'_, T> {
+        self.list.iter_mut()
+    }
+}
+
+// SAFETY: LinkedListViewMut only exposes element access and can't insert or remove elements
+unsafe impl<'a, T: 'a> ViewMut<'a> for collections::LinkedList<T> {
+    type MutableView = LinkedListViewMut<'a, T>;
+    fn view_mut(&'a mut self) -> Self::MutableView {
+        LinkedListViewMut { list: self }
+    }
+}
+
+/// A [`BTreeSet`](collections::BTreeSet) is ordered by its elements, [`push`](LinearSizedCollection::push) inserts
+/// the element at its position and [`pop`](LinearSizedCollection::pop) removes the largest element.
+///
+/// Pushing an element which is already in the set doesn't insert it again, the length stays the same.
+/// [`SizeRestricted::push`] succeeds without growing the collection in this case, even if it is already at `MAX`.
+/// Filling a set up to a length (e.g. in [`SizeRestricted::new_fit`]) panics if a fill value is already in the set.
+///
+/// Mutating elements of a set could change their order so it doesn't implement [`ViewMut`].
+impl<T: Ord> LinearSizedCollection<T> for collections::BTreeSet<T> {
     fn len(&self) -> usize {
         self.len()
     }
 
+    fn push(&mut self, val: T) {
+        self.insert(val);
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        self.pop_last()
+    }
+
+    fn grows_on_push(&self, val: &T) -> bool {
+        !self.contains(val)
+    }
+
+    fn shrink_to(&mut self, len: usize) {
+        while self.len() > len {
+            self.pop_last();
+        }
+    }
+
+    /// # Panics
+    ///
+    /// Panics if a value returned by `fill` is already in the set as the set wouldn't grow.
+    fn extend_to_with<F: FnMut() -> T>(&mut self, len: usize, mut fill: F) {
+        while self.len() < len {
+            assert!(
+                self.insert(fill()),
+                "Values used to fill a BTreeSet have to be unique"
+            );
+        }
+    }
+
+    fn reserve(&mut self, _additional: usize) {}
+
+    fn count_retained<F: FnMut(&T) -> bool>(&mut self, mut f: F) -> usize {
+        self.iter().filter(|val| f(val)).count()
+    }
+
+    fn retain<F: FnMut(&T) -> bool>(&mut self, f: F) {
+        self.retain(f);
+    }
+}
+
+impl<'a, T: 'a> Iterable<'a, T> for collections::BTreeSet<T> {
+    type Iter = collections::btree_set::Iter<'a, T>;
+    fn iter(&'a self) -> Self::Iter {
+        collections::BTreeSet::iter(self)
+    }
+}
+
+/// The length of a [`String`] is its number of [`char`]s, not its length in bytes.
+///
+/// A [`String`] doesn't store its char count so [`len`](LinearSizedCollection::len) and
+/// [`shrink_to`](LinearSizedCollection::shrink_to) are O(n).
+impl LinearSizedCollection<char> for String {
+    fn len(&self) -> usize {
+        self.chars().count()
+    }
+
+    fn shrink_to(&mut self, len: usize) {
+        if let Some((idx, _)) = self.char_indices().nth(len) {
+            self.truncate(idx);
+        }
+    }
+
     fn pop(&mut self) -> Option<char> {
         self.pop()
     }
@@ -97,13 +610,418 @@ impl LinearSizedCollection<char> for String {
     fn reserve(&mut self, additional: usize) {
         self.reserve(additional);
     }
+
+    fn reserve_exact(&mut self, additional: usize) {
+        self.reserve_exact(additional);
+    }
+
+    fn try_reserve(&mut self, additional: usize) -> Result<(), ReserveError> {
+        self.try_reserve(additional).map_err(|_| ReserveError)
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.shrink_to_fit();
+    }
+
+    fn swap_remove(&mut self, index: usize) -> Option<char> {
+        let (idx, _) = self.char_indices().nth(index)?;
+        let last = self.pop()?;
+        if idx == self.len() {
+            return Some(last);
+        }
+
+        let removed = self.remove(idx);
+        self.insert(idx, last);
+        Some(removed)
+    }
+
+    fn count_retained<F: FnMut(&char) -> bool>(&mut self, mut f: F) -> usize {
+        self.chars().filter(|c| f(c)).count()
+    }
+
+    fn retain<F: FnMut(&char) -> bool>(&mut self, mut f: F) {
+        self.retain(|c| f(&c));
+    }
+}
+
+/// Parses a string while checking the char count against the size restriction
+impl<const MIN: usize, const MAX: usize> FromStr for SizeRestricted<char, String, MIN, MAX> {
+    type Err = SizeRangeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(String::from(s)).map_err(|(e, _)| e)
+    }
+}
+
+/// Writes a string while checking the char count against `MAX`.
+///
+/// Every [`write_str`](core::fmt::Write::write_str) call either appends the whole string or nothing and returns
+/// [`core::fmt::Error`] if the string doesn't fit. A [`write!`] is made of multiple calls, so the pieces which got
+/// written before the error are kept.
+impl<const MIN: usize, const MAX: usize> core::fmt::Write
+    for SizeRestricted<char, String, MIN, MAX>
+{
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        if s.chars().count() > MAX - self.len() {
+            return Err(core::fmt::Error);
+        }
+
+        self.collection.push_str(s);
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod test {
     mod linear_alloc_collection_test {
-        crate::test::complete_test!(Vec::new(), vec_test);
+        crate::test::complete_test!(alloc::vec::Vec::new(), vec_test);
         crate::test::complete_test!(alloc::collections::VecDeque::new(), vecdeque_test);
         crate::test::complete_test!(alloc::collections::LinkedList::new(), linkedlist_test);
+        crate::test::complete_test!(crate::FrontDeque::default(), front_deque_test);
+        crate::test::iterable_test!(crate::FrontDeque::default(), front_deque_iterable_test);
+        crate::test::complete_set_test!(alloc::collections::BTreeSet::new(), btree_set_test);
+        crate::test::iterable_test!(alloc::vec::Vec::new(), vec_iterable_test);
+        crate::test::iterable_test!(alloc::collections::VecDeque::new(), vecdeque_iterable_test);
+        crate::test::iterable_test!(
+            alloc::collections::LinkedList::new(),
+            linkedlist_iterable_test
+        );
+        crate::test::linear_deque_test!(alloc::collections::VecDeque::new(), vecdeque_deque_test);
+        crate::test::linear_deque_test!(
+            alloc::collections::LinkedList::new(),
+            linkedlist_deque_test
+        );
+    }
+
+    mod iterable_test {
+        use alloc::{
+            collections::{LinkedList, VecDeque},
+            vec,
+            vec::Vec,
+        };
+
+        use crate::SizeRestricted;
+
+        #[test]
+        fn count() {
+            let values = [1, 2, 3, 4, 5, 6, 8];
+            let vec = SizeRestricted::<i32, _, 1, 10>::new(vec![1, 2, 3, 4, 5, 6, 8]).unwrap();
+            let deque = SizeRestricted::<i32, _, 1, 10>::new(VecDeque::from(values)).unwrap();
+            let list = SizeRestricted::<i32, _, 1, 10>::new(LinkedList::from(values)).unwrap();
+
+            assert_eq!(vec.count(|val| val % 2 == 0), 4);
+            assert_eq!(deque.count(|val| val % 2 == 0), 4);
+            assert_eq!(list.count(|val| val % 2 == 0), 4);
+            assert_eq!(list.count(|val| *val > 10), 0);
+        }
+
+        #[test]
+        fn enumerate() {
+            let values = ['a', 'b', 'c'];
+            let expected = vec![(0, &'a'), (1, &'b'), (2, &'c')];
+            let vec = SizeRestricted::<char, _, 1, 10>::new(vec!['a', 'b', 'c']).unwrap();
+            let deque = SizeRestricted::<char, _, 1, 10>::new(VecDeque::from(values)).unwrap();
+            let list = SizeRestricted::<char, _, 1, 10>::new(LinkedList::from(values)).unwrap();
+
+            assert_eq!(vec.enumerate().collect::<Vec<_>>(), expected);
+            assert_eq!(deque.enumerate().collect::<Vec<_>>(), expected);
+            assert_eq!(list.enumerate().collect::<Vec<_>>(), expected);
+        }
+    }
+
+    mod binary_search_test {
+        use alloc::collections::{LinkedList, VecDeque};
+
+        use crate::BinarySearch;
+
+        #[test]
+        fn matches_slice() {
+            let values = [1, 3, 5, 8, 13];
+            let deque = VecDeque::from(values);
+            let list = LinkedList::from(values);
+            for x in 0..15 {
+                let expected = values.binary_search(&x);
+                assert_eq!(
+                    BinarySearch::binary_search_by(&deque, |e| e.cmp(&x)),
+                    expected
+                );
+                assert_eq!(
+                    BinarySearch::binary_search_by(&list, |e| e.cmp(&x)),
+                    expected
+                );
+            }
+        }
+
+        #[test]
+        fn partition_point_matches_slice() {
+            let values = [1, 3, 3, 5, 8, 13];
+            let deque = VecDeque::from(values);
+            let list = LinkedList::from(values);
+            for x in 0..15 {
+                let expected = values.partition_point(|e| *e < x);
+                assert_eq!(BinarySearch::partition_point(&deque, |e| *e < x), expected);
+                assert_eq!(BinarySearch::partition_point(&list, |e| *e < x), expected);
+            }
+        }
+    }
+
+    mod as_slices_test {
+        use alloc::collections::VecDeque;
+
+        use crate::SizeRestricted;
+
+        #[test]
+        fn keeps_layout() {
+            let mut deque = VecDeque::with_capacity(4);
+            deque.push_back(2);
+            deque.push_back(3);
+            deque.push_front(1);
+            let layout = deque.as_slices();
+            assert!(!layout.1.is_empty());
+            let layout = (layout.0.to_vec(), layout.1.to_vec());
+
+            let collection = SizeRestricted::<i32, _, 1, 4>::new(deque).unwrap();
+            let (front, back) = collection.as_slices();
+            assert_eq!((front.to_vec(), back.to_vec()), layout);
+            let (front, back) = collection.inner().as_slices();
+            assert_eq!((front.to_vec(), back.to_vec()), layout);
+        }
+    }
+
+    mod boxed_slice_test {
+        use alloc::{boxed::Box, vec};
+
+        use crate::{ExactSized, SizeRangeError, SizeRestricted};
+
+        #[test]
+        fn exact_sized() {
+            let boxed: Box<[u8]> = vec![1, 2, 3, 4].into_boxed_slice();
+            let mut collection = ExactSized::<u8, _, 4>::new(boxed).unwrap();
+            assert_eq!(collection.as_slice(), &[1, 2, 3, 4]);
+
+            collection.view_mut()[0] = 10;
+            *collection.last_mut_nonempty() = 40;
+            assert_eq!(collection.as_slice(), &[10, 2, 3, 40]);
+            assert_eq!(collection.pop(), None);
+            assert_eq!(collection.push(5), Err((SizeRangeError::TooLarge, 5)));
+        }
+
+        #[test]
+        fn wrong_size() {
+            let boxed: Box<[u8]> = vec![0; 8].into_boxed_slice();
+            let (e, _) = SizeRestricted::<u8, _, 0, 4>::new(boxed).unwrap_err();
+            assert_eq!(e, SizeRangeError::TooLarge);
+        }
+
+        #[test]
+        fn keep_length() {
+            let boxed: Box<[u8]> = vec![1, 2, 3, 4].into_boxed_slice();
+            let mut collection = ExactSized::<u8, _, 4>::new(boxed).unwrap();
+
+            collection.retain(|_| true).unwrap();
+            collection
+                .retain_mut(|val| {
+                    *val |= 8;
+                    true
+                })
+                .unwrap();
+            assert!(collection.extract_if(|_| false).unwrap().is_empty());
+            collection.dedup_consecutive(|a, b| a == b).unwrap();
+            collection.select(&[0, 1, 2, 3]).unwrap();
+            assert_eq!(collection.as_slice(), &[9, 10, 11, 12]);
+
+            assert_eq!(
+                collection.retain(|&val| val > 9),
+                Err(SizeRangeError::TooSmall)
+            );
+            assert_eq!(collection.swap_remove(4), None);
+            assert_eq!(collection.as_slice(), &[9, 10, 11, 12]);
+        }
+    }
+
+    mod peek_test {
+        use alloc::collections::VecDeque;
+
+        use crate::SizeRestricted;
+
+        #[test]
+        fn peek_doesnt_remove() {
+            let deque = VecDeque::from([1, 2, 3]);
+            let mut queue = SizeRestricted::<i32, _, 1, 4>::new(deque).unwrap();
+            assert_eq!(queue.peek_front(), Some(&1));
+            assert_eq!(queue.peek_back(), Some(&3));
+            assert_eq!(queue.peek_front(), queue.first());
+            assert_eq!(queue.peek_back(), queue.last());
+
+            *queue.peek_front_mut().unwrap() = 0;
+            *queue.peek_back_mut().unwrap() = 4;
+            assert_eq!(queue.inner(), &VecDeque::from([0, 2, 4]));
+            assert_eq!(queue.len(), 3);
+        }
+    }
+
+    mod truncate_front_test {
+        use alloc::collections::VecDeque;
+
+        use crate::SizeRestricted;
+
+        #[test]
+        fn keeps_newest() {
+            let deque = VecDeque::from([1, 2, 3, 4, 5]);
+            let mut collection = SizeRestricted::<i32, _, 2, 8>::new(deque).unwrap();
+            collection.truncate_front(3);
+            assert_eq!(collection.inner(), &VecDeque::from([3, 4, 5]));
+
+            collection.truncate_front(0);
+            assert_eq!(collection.inner(), &VecDeque::from([4, 5]));
+
+            collection.truncate_front(4);
+            assert_eq!(collection.inner(), &VecDeque::from([4, 5]));
+        }
+    }
+
+    mod linkedlist_view_test {
+        use alloc::collections::LinkedList;
+
+        use crate::SizeRestricted;
+
+        #[test]
+        fn mutate_through_view() {
+            let list = LinkedList::from([1, 2, 3]);
+            let mut restricted = SizeRestricted::<i32, _, 1, 5>::new(list).unwrap();
+
+            let mut view = restricted.view_mut();
+            *view.front_mut().unwrap() = 10;
+            *view.get_mut(1).unwrap() += 10;
+            *view.back_mut().unwrap() = 30;
+            for val in view.iter_mut() {
+                *val += 1;
+            }
+            assert_eq!(view.len(), 3);
+            assert!(view.get_mut(3).is_none());
+
+            assert_eq!(restricted.len(), 3);
+            assert_eq!(restricted.inner(), &LinkedList::from([11, 13, 31]));
+        }
+    }
+
+    mod string_test {
+        use alloc::string::String;
+
+        use crate::{LinearSizedCollection, NonEmptyString, SizeRangeError, SizeRestricted};
+
+        #[test]
+        fn write_within_bound() {
+            use core::fmt::Write;
+
+            let mut s = "a".parse::<SizeRestricted<char, String, 1, 8>>().unwrap();
+            let (num, c) = (1, 'é');
+            write!(s, "-{}-{}", num, c).unwrap();
+            assert_eq!(s.inner(), "a-1-é");
+        }
+
+        #[test]
+        fn write_beyond_bound() {
+            use core::fmt::Write;
+
+            let mut s = "ab".parse::<SizeRestricted<char, String, 1, 4>>().unwrap();
+            assert!(s.write_str("cde").is_err());
+            assert_eq!(s.inner(), "ab");
+
+            let (c, rest) = ('c', "de");
+            assert!(write!(s, "{}{}", c, rest).is_err());
+            assert_eq!(s.inner(), "abc");
+        }
+
+        #[test]
+        fn len_counts_chars() {
+            let s = String::from("héllo");
+            assert_eq!(LinearSizedCollection::len(&s), 5);
+        }
+
+        #[test]
+        fn shrink_extend_multibyte() {
+            let mut s = String::from("héllo");
+            LinearSizedCollection::shrink_to(&mut s, 2);
+            assert_eq!(s, "hé");
+            LinearSizedCollection::extend_to(&mut s, 4, 'ö');
+            assert_eq!(s, "héöö");
+            assert_eq!(LinearSizedCollection::len(&s), 4);
+        }
+
+        #[test]
+        fn bounds_use_char_count() {
+            let fits = SizeRestricted::<char, _, 1, 5>::new(String::from("héllo")).unwrap();
+            assert_eq!(fits.len(), 5);
+
+            let too_large =
+                SizeRestricted::<char, _, 1, 4>::new(String::from("héllo")).unwrap_err();
+            assert_eq!(too_large.0, SizeRangeError::TooLarge);
+
+            let too_small = SizeRestricted::<char, _, 3, 10>::new(String::from("éé")).unwrap_err();
+            assert_eq!(too_small.0, SizeRangeError::TooSmall);
+        }
+
+        #[test]
+        fn parse() {
+            let name: NonEmptyString = "héllo".parse().unwrap();
+            assert_eq!(name.inner(), "héllo");
+
+            assert_eq!("".parse::<NonEmptyString>(), Err(SizeRangeError::TooSmall));
+            assert_eq!(
+                "héllo".parse::<SizeRestricted<char, String, 1, 4>>(),
+                Err(SizeRangeError::TooLarge)
+            );
+            assert!("ééé".parse::<SizeRestricted<char, String, 1, 3>>().is_ok());
+        }
+
+        #[test]
+        fn swap_remove_multibyte() {
+            let mut s = String::from("héllö");
+            assert_eq!(LinearSizedCollection::swap_remove(&mut s, 1), Some('é'));
+            assert_eq!(s, "höll");
+            assert_eq!(LinearSizedCollection::swap_remove(&mut s, 3), Some('l'));
+            assert_eq!(s, "höl");
+        }
+    }
+
+    mod front_deque_test {
+        use alloc::collections::VecDeque;
+
+        use crate::{FrontDeque, SizeRestricted};
+
+        #[test]
+        fn push_pop_front() {
+            let mut collection =
+                SizeRestricted::<i32, _, 1, 3>::new(FrontDeque(VecDeque::from([1]))).unwrap();
+            collection.push(2).unwrap();
+            collection.push(3).unwrap();
+            assert!(collection.push(4).is_err());
+            assert_eq!(collection.inner().0, VecDeque::from([3, 2, 1]));
+
+            assert_eq!(collection.pop(), Some(3));
+            assert!(collection.iter().eq(&[1, 2]));
+        }
+
+        #[test]
+        fn index_in_push_order() {
+            let mut collection =
+                SizeRestricted::<i32, _, 0, 8>::new(FrontDeque(VecDeque::from([2, 1]))).unwrap();
+            collection.push(3).unwrap();
+            assert_eq!(collection.swap_remove(0), Some(1));
+            assert!(collection.iter().eq(&[3, 2]));
+            assert_eq!(collection.retain(|&val| val == 3), Ok(()));
+            assert!(collection.iter().eq(&[3]));
+        }
+
+        #[test]
+        fn shrink_from_front() {
+            let collection =
+                SizeRestricted::<i32, _, 0, 2>::new_truncating(FrontDeque::from(VecDeque::from([
+                    1, 2, 3, 4,
+                ])))
+                .unwrap();
+            assert_eq!(collection.inner().0, VecDeque::from([3, 4]));
+        }
     }
 }
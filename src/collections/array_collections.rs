@@ -0,0 +1,406 @@
+//! An array backed [`LinearSizedCollection`] which doesn't need an allocator
+
+use core::{
+    cmp::Ordering,
+    fmt,
+    mem::MaybeUninit,
+    ops::{Deref, DerefMut},
+};
+
+use crate::{BinarySearch, FixedCapacity, Iterable, IterableMut, LinearSizedCollection, ViewMut};
+
+/// A vector which stores up to `N` elements inline in an array.
+///
+/// [`push`](InlineVec::push) panics if the [`InlineVec`] is full. A [`SizeRestricted`](crate::SizeRestricted) with
+/// a `MAX` of at most `N` never pushes into a full [`InlineVec`].
+pub struct InlineVec<T, const N: usize> {
+    /// The elements, only the first `len` elements are initialized
+    data: [MaybeUninit<T>; N],
+    /// The number of initialized elements
+    len: usize,
+}
+
+impl<T, const N: usize> InlineVec<T, N> {
+    /// Create a new empty [`InlineVec`]
+    pub const fn new() -> Self {
+        Self {
+            data: [const { MaybeUninit::uninit() }; N],
+            len: 0,
+        }
+    }
+
+    /// Get the number of elements
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check wether the [`InlineVec`] is empty
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Check wether the [`InlineVec`] is full
+    pub const fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Get the initialized elements as slice
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: The first `len` elements are initialized
+        unsafe { core::slice::from_raw_parts(self.data.as_ptr().cast::<T>(), self.len) }
+    }
+
+    /// Get the initialized elements as mutable slice
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: The first `len` elements are initialized
+        unsafe { core::slice::from_raw_parts_mut(self.data.as_mut_ptr().cast::<T>(), self.len) }
+    }
+
+    /// Get the uninitialized elements after the first `len` elements, they can be initialized and included with
+    /// [`set_len`](InlineVec::set_len).
+    pub fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        &mut self.data[self.len..]
+    }
+
+    /// Set the length without initializing or dropping any element.
+    ///
+    /// # Safety
+    ///
+    /// `len` has to be at most `N` and the elements in `0..len` must be initialized.
+    pub unsafe fn set_len(&mut self, len: usize) {
+        debug_assert!(len <= N, "The length of an InlineVec can't exceed N");
+        self.len = len;
+    }
+
+    /// Push `val` to the end.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the [`InlineVec`] already holds `N` elements.
+    pub fn push(&mut self, val: T) {
+        assert!(!self.is_full(), "Can't push to a full InlineVec");
+        self.data[self.len].write(val);
+        self.len += 1;
+    }
+
+    /// Pop the last element or return [`None`] if the [`InlineVec`] is empty
+    pub fn pop(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        self.len -= 1;
+        // SAFETY: The element at `len` was initialized and is no longer tracked after decreasing `len`
+        Some(unsafe { self.data[self.len].assume_init_read() })
+    }
+
+    /// Drop all elements after the first `len` elements
+    pub fn truncate(&mut self, len: usize) {
+        while self.len > len {
+            self.pop();
+        }
+    }
+}
+
+impl<T, const N: usize> Drop for InlineVec<T, N> {
+    fn drop(&mut self) {
+        // SAFETY: The first `len` elements are initialized and never used again
+        unsafe { core::ptr::drop_in_place(self.as_mut_slice()) }
+    }
+}
+
+/// Only the initialized elements are cloned, the uninitialized rest of the array is never read.
+impl<T: Clone, const N: usize> Clone for InlineVec<T, N> {
+    fn clone(&self) -> Self {
+        let mut cloned = Self::new();
+        for val in self.as_slice() {
+            cloned.push(val.clone());
+        }
+
+        cloned
+    }
+}
+
+impl<T, const N: usize> Default for InlineVec<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Deref for InlineVec<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<T, const N: usize> DerefMut for InlineVec<T, N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for InlineVec<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.as_slice()).finish()
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq for InlineVec<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for InlineVec<T, N> {}
+
+impl<T, const N: usize> LinearSizedCollection<T> for InlineVec<T, N> {
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn push(&mut self, val: T) {
+        self.push(val);
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        self.pop()
+    }
+
+    fn shrink_to(&mut self, len: usize) {
+        self.truncate(len);
+    }
+
+    fn reserve(&mut self, _additional: usize) {}
+
+    unsafe fn set_len(&mut self, len: usize) {
+        // SAFETY: Guaranteed by the caller
+        unsafe { self.set_len(len) }
+    }
+
+    fn swap_remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.len {
+            return None;
+        }
+
+        let last = self.len - 1;
+        self.as_mut_slice().swap(index, last);
+        self.pop()
+    }
+
+    fn count_retained<F: FnMut(&T) -> bool>(&mut self, mut f: F) -> usize {
+        self.as_slice().iter().filter(|val| f(val)).count()
+    }
+
+    fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, f: F) {
+        let retained = super::retain_mut_in_place(self.as_mut_slice(), f);
+        self.truncate(retained);
+    }
+}
+
+impl<T, const N: usize> FixedCapacity for InlineVec<T, N> {
+    const CAPACITY: usize = N;
+}
+
+// SAFETY: A slice can't mutate its containers length
+unsafe impl<'a, T: 'a, const N: usize> ViewMut<'a> for InlineVec<T, N> {
+    type MutableView = &'a mut [T];
+    fn view_mut(&'a mut self) -> Self::MutableView {
+        self.as_mut_slice()
+    }
+}
+
+impl<'a, T: 'a, const N: usize> Iterable<'a, T> for InlineVec<T, N> {
+    type Iter = core::slice::Iter<'a, T>;
+    fn iter(&'a self) -> Self::Iter {
+        self.as_slice().iter()
+    }
+}
+
+// SAFETY: Iterating over mutable references can't mutate the length
+unsafe impl<'a, T: 'a, const N: usize> IterableMut<'a, T> for InlineVec<T, N> {
+    type IterMut = core::slice::IterMut<'a, T>;
+    fn iter_mut(&'a mut self) -> Self::IterMut {
+        self.as_mut_slice().iter_mut()
+    }
+}
+
+impl<T, const N: usize> BinarySearch<T> for InlineVec<T, N> {
+    fn binary_search_by<F: FnMut(&T) -> Ordering>(&self, f: F) -> Result<usize, usize> {
+        self.as_slice().binary_search_by(f)
+    }
+}
+
+#[cfg(feature = "impl_serde")]
+impl<T: serde::Serialize, const N: usize> serde::Serialize for InlineVec<T, N> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_seq(self.as_slice())
+    }
+}
+
+/// Visitor to deserialize an [`InlineVec`] from a sequence without overflowing the array
+#[cfg(feature = "impl_serde")]
+struct InlineVecVisitor<T, const N: usize>(core::marker::PhantomData<T>);
+
+#[cfg(feature = "impl_serde")]
+impl<'de, T: serde::Deserialize<'de>, const N: usize> serde::de::Visitor<'de>
+    for InlineVecVisitor<T, N>
+{
+    type Value = InlineVec<T, N>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a sequence of at most {} elements", N)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        let mut collection = InlineVec::new();
+        while let Some(val) = seq.next_element()? {
+            if collection.is_full() {
+                return Err(serde::de::Error::invalid_length(N + 1, &self));
+            }
+            collection.push(val);
+        }
+
+        Ok(collection)
+    }
+}
+
+/// Deserializing a sequence with more than `N` elements fails with an invalid length error
+#[cfg(feature = "impl_serde")]
+impl<'de, T: serde::Deserialize<'de>, const N: usize> serde::Deserialize<'de> for InlineVec<T, N> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(InlineVecVisitor(core::marker::PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{InlineVec, SizeRestricted};
+
+    mod linear_array_collection_test {
+        crate::test::complete_test!(crate::InlineVec::<i32, 128>::new(), inline_vec_test);
+        crate::test::iterable_test!(
+            crate::InlineVec::<i32, 128>::new(),
+            inline_vec_iterable_test
+        );
+    }
+
+    type Restricted = SizeRestricted<i32, InlineVec<i32, 8>, 0, 4>;
+
+    const _: usize = Restricted::CAPACITY;
+    const MAX_CAPACITY: usize = Restricted::max_capacity();
+
+    #[test]
+    fn capacity() {
+        assert_eq!(Restricted::CAPACITY, 8);
+        assert_eq!(MAX_CAPACITY, 4);
+        assert_eq!(
+            SizeRestricted::<i32, InlineVec<i32, 8>, 0, 16>::max_capacity(),
+            8
+        );
+    }
+
+    #[test]
+    fn restricted_to_capacity() {
+        let mut collection =
+            SizeRestricted::<i32, _, 0, 4>::new(InlineVec::<i32, 4>::new()).unwrap();
+        for i in 0..4 {
+            collection.push(i).unwrap();
+        }
+        assert!(collection.push(4).is_err());
+        assert_eq!(collection.as_slice(), &[0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn set_len_unchecked() {
+        let mut inline = InlineVec::<u8, 4>::new();
+        for (i, elem) in inline.spare_capacity_mut().iter_mut().enumerate() {
+            elem.write(i as u8);
+        }
+
+        let mut collection = SizeRestricted::<u8, _, 0, 3>::new(inline).unwrap();
+        // SAFETY: 3 is in the bounds and the first 3 elements were initialized
+        unsafe { collection.set_len_unchecked(3) };
+        assert_eq!(collection.as_slice(), &[0, 1, 2]);
+    }
+
+    #[test]
+    fn clone_initialized_prefix() {
+        use core::cell::Cell;
+
+        #[derive(Debug)]
+        struct Tracked<'a>(&'a Cell<usize>);
+
+        impl Clone for Tracked<'_> {
+            fn clone(&self) -> Self {
+                self.0.set(self.0.get() + 1);
+                Self(self.0)
+            }
+        }
+
+        let clones = Cell::new(0);
+        let mut collection = InlineVec::<_, 8>::new();
+        collection.push(Tracked(&clones));
+        collection.push(Tracked(&clones));
+        collection.push(Tracked(&clones));
+
+        let restricted = SizeRestricted::<_, _, 3, 3>::new(collection).unwrap();
+        let cloned = restricted.clone();
+        assert_eq!(clones.get(), 3);
+        assert_eq!(cloned.len(), 3);
+    }
+
+    #[test]
+    fn drops_elements() {
+        use alloc::rc::Rc;
+
+        let counted = Rc::new(());
+        let mut collection = InlineVec::<_, 4>::new();
+        collection.push(Rc::clone(&counted));
+        collection.push(Rc::clone(&counted));
+        assert_eq!(Rc::strong_count(&counted), 3);
+
+        drop(collection.pop());
+        assert_eq!(Rc::strong_count(&counted), 2);
+        drop(collection);
+        assert_eq!(Rc::strong_count(&counted), 1);
+    }
+
+    #[cfg(feature = "impl_serde")]
+    mod serde_test {
+        use alloc::string::ToString;
+
+        use crate::{ExactSized, InlineVec};
+
+        type Exact = ExactSized<u8, InlineVec<u8, 4>, 4>;
+
+        #[test]
+        fn deserialize_exact() {
+            let collection = serde_json::from_str::<Exact>("[1, 2, 3, 4]").unwrap();
+            assert_eq!(collection.as_slice(), &[1, 2, 3, 4]);
+            assert_eq!(serde_json::to_string(&collection).unwrap(), "[1,2,3,4]");
+        }
+
+        #[test]
+        fn deserialize_too_small() {
+            let e = serde_json::from_str::<Exact>("[1, 2, 3]").unwrap_err();
+            assert!(e.to_string().contains("Too Small"), "{}", e);
+        }
+
+        #[test]
+        fn deserialize_too_large() {
+            let e = serde_json::from_str::<Exact>("[1, 2, 3, 4, 5]").unwrap_err();
+            assert!(e.to_string().contains("invalid length 5"), "{}", e);
+        }
+    }
+}
@@ -0,0 +1,237 @@
+//! A gap buffer implementing [`LinearSizedCollection`]
+
+use alloc::vec::Vec;
+use core::{fmt, iter, slice};
+
+use crate::{Iterable, IterableMut, LinearSizedCollection, ViewMut};
+
+/// A gap buffer as used in text editors. Inserting and removing elements at the gap is amortized O(1),
+/// moving the gap is O(n) in the distance it is moved.
+///
+/// [`LinearSizedCollection::push`] and [`LinearSizedCollection::pop`] operate at the end of the buffer, they move
+/// the gap to the end first which means consecutive pushes and pops are amortized O(1).
+#[derive(Clone)]
+pub struct GapBuffer<T> {
+    /// The elements before the gap in order
+    before: Vec<T>,
+    /// The elements after the gap in reversed order, the element right after the gap is the last element
+    after: Vec<T>,
+}
+
+impl<T> GapBuffer<T> {
+    /// Create a new empty [`GapBuffer`]
+    pub const fn new() -> Self {
+        Self {
+            before: Vec::new(),
+            after: Vec::new(),
+        }
+    }
+
+    /// Create a new empty [`GapBuffer`] which can hold `capacity` elements before the gap without reallocating
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            before: Vec::with_capacity(capacity),
+            after: Vec::new(),
+        }
+    }
+
+    /// Get the number of elements
+    pub fn len(&self) -> usize {
+        self.before.len() + self.after.len()
+    }
+
+    /// Check wether the [`GapBuffer`] is empty
+    pub fn is_empty(&self) -> bool {
+        self.before.is_empty() && self.after.is_empty()
+    }
+
+    /// Get the position of the gap, this is the number of elements before the gap
+    pub fn gap(&self) -> usize {
+        self.before.len()
+    }
+
+    /// Move the gap so that `index` elements are before it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is larger than the length of the [`GapBuffer`].
+    pub fn move_gap(&mut self, index: usize) {
+        assert!(
+            index <= self.len(),
+            "Can't move the gap out of the GapBuffer"
+        );
+        while self.before.len() > index {
+            self.after.extend(self.before.pop());
+        }
+        while self.before.len() < index {
+            self.before.extend(self.after.pop());
+        }
+    }
+
+    /// Insert `val` at the gap, the gap is moved behind the inserted element
+    pub fn insert(&mut self, val: T) {
+        self.before.push(val);
+    }
+
+    /// Remove the element right before the gap
+    pub fn remove_before(&mut self) -> Option<T> {
+        self.before.pop()
+    }
+
+    /// Remove the element right after the gap
+    pub fn remove_after(&mut self) -> Option<T> {
+        self.after.pop()
+    }
+
+    /// Iterate over all elements in order
+    pub fn iter(&self) -> GapBufferIter<'_, T> {
+        self.before.iter().chain(self.after.iter().rev())
+    }
+
+    /// Iterate over mutable references to all elements in order
+    pub fn iter_mut(&mut self) -> GapBufferIterMut<'_, T> {
+        self.before.iter_mut().chain(self.after.iter_mut().rev())
+    }
+
+    /// Close the gap by moving it to the end and get all elements as contiguous slice
+    pub fn make_contiguous(&mut self) -> &mut [T] {
+        self.move_gap(self.len());
+        &mut self.before
+    }
+}
+
+/// An iterator over the elements of a [`GapBuffer`]
+pub type GapBufferIter<'a, T> = iter::Chain<slice::Iter<'a, T>, iter::Rev<slice::Iter<'a, T>>>;
+
+/// An iterator over mutable references to the elements of a [`GapBuffer`]
+pub type GapBufferIterMut<'a, T> =
+    iter::Chain<slice::IterMut<'a, T>, iter::Rev<slice::IterMut<'a, T>>>;
+
+impl<T> Default for GapBuffer<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for GapBuffer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// Two [`GapBuffer`]s are equal if they contain the same elements, the position of the gap is ignored
+impl<T: PartialEq> PartialEq for GapBuffer<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for GapBuffer<T> {}
+
+impl<T> LinearSizedCollection<T> for GapBuffer<T> {
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn push(&mut self, val: T) {
+        self.move_gap(self.len());
+        self.before.push(val);
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        self.move_gap(self.len());
+        self.before.pop()
+    }
+
+    fn shrink_to(&mut self, len: usize) {
+        self.move_gap(self.len());
+        self.before.truncate(len);
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.before.reserve(additional);
+    }
+
+    fn count_retained<F: FnMut(&T) -> bool>(&mut self, mut f: F) -> usize {
+        self.iter().filter(|val| f(val)).count()
+    }
+
+    fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, f: F) {
+        self.make_contiguous();
+        self.before.retain_mut(f);
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.before.shrink_to_fit();
+        self.after.shrink_to_fit();
+    }
+}
+
+// SAFETY: A slice can't mutate its containers length
+unsafe impl<'a, T: 'a> ViewMut<'a> for GapBuffer<T> {
+    type MutableView = &'a mut [T];
+    fn view_mut(&'a mut self) -> Self::MutableView {
+        self.make_contiguous()
+    }
+}
+
+impl<'a, T: 'a> Iterable<'a, T> for GapBuffer<T> {
+    type Iter = GapBufferIter<'a, T>;
+    fn iter(&'a self) -> Self::Iter {
+        GapBuffer::iter(self)
+    }
+}
+
+// SAFETY: Iterating over mutable references can't mutate the length
+unsafe impl<'a, T: 'a> IterableMut<'a, T> for GapBuffer<T> {
+    type IterMut = GapBufferIterMut<'a, T>;
+    fn iter_mut(&'a mut self) -> Self::IterMut {
+        GapBuffer::iter_mut(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::vec::Vec;
+
+    use crate::{GapBuffer, SizeRestricted};
+
+    mod linear_gap_collection_test {
+        crate::test::complete_test!(crate::GapBuffer::new(), gap_buffer_test);
+        crate::test::iterable_test!(crate::GapBuffer::new(), gap_buffer_iterable_test);
+    }
+
+    #[test]
+    fn edit_at_gap() {
+        let mut buffer = GapBuffer::new();
+        buffer.insert('a');
+        buffer.insert('c');
+        buffer.move_gap(1);
+        buffer.insert('b');
+        assert_eq!(buffer.gap(), 2);
+        assert_eq!(buffer.remove_after(), Some('c'));
+        buffer.insert('d');
+        assert_eq!(buffer.iter().collect::<Vec<_>>(), [&'a', &'b', &'d']);
+
+        buffer.move_gap(0);
+        for val in buffer.iter_mut() {
+            val.make_ascii_uppercase();
+        }
+        assert_eq!(buffer.iter().rev().collect::<Vec<_>>(), [&'D', &'B', &'A']);
+    }
+
+    #[test]
+    fn view_mut_closes_gap() {
+        let mut buffer = GapBuffer::new();
+        buffer.insert(1);
+        buffer.insert(3);
+        buffer.move_gap(1);
+        buffer.insert(2);
+
+        let mut collection = SizeRestricted::<i32, _, 1, 4>::new(buffer).unwrap();
+        assert_eq!(collection.view_mut(), &mut [1, 2, 3]);
+        collection.push(4).unwrap();
+        assert!(collection.push(5).is_err());
+        assert_eq!(collection.into_inner().gap(), 4);
+    }
+}
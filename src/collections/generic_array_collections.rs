@@ -0,0 +1,126 @@
+//! Implementations of [`LinearSizedCollection`] for [`generic_array`] types
+
+use generic_array::{ArrayLength, GenericArray};
+
+use crate::{FixedCapacity, Iterable, IterableMut, LinearSizedCollection, ReserveError, ViewMut};
+
+/// A [`GenericArray`] always has exactly `N` elements, so [`push`](LinearSizedCollection::push) and
+/// [`pop`](LinearSizedCollection::pop) panic. Every operation which changes the length panics as well.
+///
+/// Only an [`ExactSized`](crate::ExactSized) collection with a size of `N` is meaningful for a [`GenericArray`],
+/// as it never has to change the length.
+impl<T, N: ArrayLength> LinearSizedCollection<T> for GenericArray<T, N> {
+    fn len(&self) -> usize {
+        N::USIZE
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        panic!("Can't pop from a GenericArray as its length is fixed")
+    }
+
+    fn push(&mut self, _val: T) {
+        panic!("Can't push to a GenericArray as its length is fixed")
+    }
+
+    fn reserve(&mut self, _additional: usize) {}
+
+    fn try_reserve(&mut self, additional: usize) -> Result<(), ReserveError> {
+        if additional == 0 {
+            Ok(())
+        } else {
+            Err(ReserveError)
+        }
+    }
+
+    fn count_retained<F: FnMut(&T) -> bool>(&mut self, mut f: F) -> usize {
+        self.as_slice().iter().filter(|val| f(val)).count()
+    }
+
+    fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, mut f: F) {
+        for val in self.iter_mut() {
+            assert!(
+                f(val),
+                "Can't remove from a GenericArray as its length is fixed"
+            );
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    fn extract_if<F: FnMut(&mut T) -> bool>(&mut self, mut f: F) -> alloc::vec::Vec<T> {
+        for val in self.iter_mut() {
+            assert!(
+                !f(val),
+                "Can't remove from a GenericArray as its length is fixed"
+            );
+        }
+
+        alloc::vec::Vec::new()
+    }
+
+    #[cfg(feature = "alloc")]
+    fn mark_deduped<F: FnMut(&T, &T) -> bool>(&mut self, same: F) -> alloc::vec::Vec<bool> {
+        super::mark_deduped_in_slice(self, same)
+    }
+
+    fn swap_remove(&mut self, index: usize) -> Option<T> {
+        if index >= N::USIZE {
+            return None;
+        }
+
+        panic!("Can't remove from a GenericArray as its length is fixed")
+    }
+}
+
+impl<T, N: ArrayLength> FixedCapacity for GenericArray<T, N> {
+    const CAPACITY: usize = N::USIZE;
+}
+
+// SAFETY: A slice can't mutate its containers length
+unsafe impl<'a, T: 'a, N: ArrayLength> ViewMut<'a> for GenericArray<T, N> {
+    type MutableView = &'a mut [T];
+    fn view_mut(&'a mut self) -> Self::MutableView {
+        self.as_mut_slice()
+    }
+}
+
+impl<'a, T: 'a, N: ArrayLength> Iterable<'a, T> for GenericArray<T, N> {
+    type Iter = core::slice::Iter<'a, T>;
+    fn iter(&'a self) -> Self::Iter {
+        self.as_slice().iter()
+    }
+}
+
+// SAFETY: Iterating over mutable references can't mutate the length
+unsafe impl<'a, T: 'a, N: ArrayLength> IterableMut<'a, T> for GenericArray<T, N> {
+    type IterMut = core::slice::IterMut<'a, T>;
+    fn iter_mut(&'a mut self) -> Self::IterMut {
+        self.as_mut_slice().iter_mut()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use generic_array::{typenum::U32, GenericArray};
+
+    use crate::{ExactSized, SizeRangeError, SizeRestricted};
+
+    #[test]
+    fn exact_sized() {
+        let array = GenericArray::<u8, U32>::from_array([7; 32]);
+        let mut collection = ExactSized::<u8, _, 32>::new(array).unwrap();
+        assert_eq!(collection.len(), 32);
+        assert_eq!(collection.as_slice(), &[7; 32]);
+
+        collection.as_mut_slice()[0] = 1;
+        assert_eq!(collection.first(), Some(&1));
+        assert_eq!(collection.pop(), None);
+        assert_eq!(collection.push(0), Err((SizeRangeError::TooLarge, 0)));
+    }
+
+    #[test]
+    fn wrong_size() {
+        let array = GenericArray::<u8, U32>::default();
+        let (e, _) = SizeRestricted::<u8, _, 0, 16>::new(array).unwrap_err();
+        assert_eq!(e, SizeRangeError::TooLarge);
+    }
+}
@@ -0,0 +1,99 @@
+//! Implementations of [`LinearSizedCollection`] for [`im`] types
+
+use core::cmp::Ordering;
+
+use im::Vector;
+
+use crate::{BinarySearch, Iterable, IterableMut, LinearSizedCollection, LinearSizedDeque};
+
+/// A [`Vector`] is a persistent collection which shares its structure between clones, this makes
+/// clones of a [`SizeRestricted`](crate::SizeRestricted) [`Vector`] cheap.
+///
+/// The elements of a [`Vector`] are not stored contiguously, so it doesn't implement [`ViewMut`](crate::ViewMut).
+/// Use [`SizeRestricted::iter_mut`](crate::SizeRestricted::iter_mut) to mutate its elements.
+impl<T: Clone> LinearSizedCollection<T> for Vector<T> {
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn push(&mut self, val: T) {
+        self.push_back(val);
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        self.pop_back()
+    }
+
+    fn shrink_to(&mut self, len: usize) {
+        if len < self.len() {
+            self.truncate(len);
+        }
+    }
+
+    fn reserve(&mut self, _additional: usize) {}
+
+    fn count_retained<F: FnMut(&T) -> bool>(&mut self, mut f: F) -> usize {
+        self.iter().filter(|val| f(val)).count()
+    }
+
+    fn retain<F: FnMut(&T) -> bool>(&mut self, f: F) {
+        self.retain(f);
+    }
+}
+
+impl<T: Clone> LinearSizedDeque<T> for Vector<T> {
+    fn push_front(&mut self, val: T) {
+        self.push_front(val);
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        self.pop_front()
+    }
+}
+
+impl<'a, T: Clone + 'a> Iterable<'a, T> for Vector<T> {
+    type Iter = im::vector::Iter<'a, T>;
+    fn iter(&'a self) -> Self::Iter {
+        Vector::iter(self)
+    }
+}
+
+// SAFETY: Iterating over mutable references can't mutate the length
+unsafe impl<'a, T: Clone + 'a> IterableMut<'a, T> for Vector<T> {
+    type IterMut = im::vector::IterMut<'a, T>;
+    fn iter_mut(&'a mut self) -> Self::IterMut {
+        Vector::iter_mut(self)
+    }
+}
+
+impl<T: Clone> BinarySearch<T> for Vector<T> {
+    fn binary_search_by<F: FnMut(&T) -> Ordering>(&self, f: F) -> Result<usize, usize> {
+        self.binary_search_by(f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use im::Vector;
+
+    use crate::SizeRestricted;
+
+    mod linear_im_collection_test {
+        crate::test::complete_test!(im::Vector::new(), vector_test);
+        crate::test::iterable_test!(im::Vector::new(), vector_iterable_test);
+        crate::test::linear_deque_test!(im::Vector::new(), vector_deque_test);
+    }
+
+    #[test]
+    fn undo_stack() {
+        let mut history = SizeRestricted::<i32, _, 1, 3>::new(Vector::unit(0)).unwrap();
+        let snapshot = history.clone();
+        history.push(1).unwrap();
+        history.push(2).unwrap();
+        assert!(history.push(3).is_err());
+
+        *history.last_mut().unwrap() = 4;
+        assert_eq!(history.inner(), &Vector::from(vec![0, 1, 4]));
+        assert_eq!(snapshot.inner(), &Vector::unit(0));
+    }
+}
@@ -0,0 +1,161 @@
+//! Implementations of [`LinearSizedCollection`] for [`indexmap`] types
+
+use core::hash::{BuildHasher, Hash};
+
+use indexmap::IndexSet;
+
+use crate::{Iterable, LinearSizedCollection};
+
+/// An [`IndexSet`] keeps its elements in insertion order, [`push`](LinearSizedCollection::push) inserts at the end and
+/// [`pop`](LinearSizedCollection::pop) removes the last inserted element.
+///
+/// Pushing an element which is already in the set doesn't insert it again, the length stays the same.
+/// [`SizeRestricted::push`](crate::SizeRestricted::push) succeeds without growing the collection in this case, even
+/// if it is already at `MAX`.
+/// Filling a set up to a length (e.g. in [`SizeRestricted::new_fit`](crate::SizeRestricted::new_fit)) panics if a
+/// fill value is already in the set, use functions with a fill function returning unique values instead.
+///
+/// Mutating elements of a set could change their hash so it doesn't implement [`ViewMut`](crate::ViewMut).
+impl<T: Hash + Eq, S: BuildHasher> LinearSizedCollection<T> for IndexSet<T, S> {
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn push(&mut self, val: T) {
+        self.insert(val);
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        self.pop()
+    }
+
+    fn grows_on_push(&self, val: &T) -> bool {
+        !self.contains(val)
+    }
+
+    fn shrink_to(&mut self, len: usize) {
+        self.truncate(len);
+    }
+
+    /// # Panics
+    ///
+    /// Panics if a value returned by `fill` is already in the set as the set wouldn't grow.
+    fn extend_to_with<F: FnMut() -> T>(&mut self, len: usize, mut fill: F) {
+        self.reserve(len.saturating_sub(self.len()));
+        while self.len() < len {
+            assert!(
+                self.insert(fill()),
+                "Values used to fill an IndexSet have to be unique"
+            );
+        }
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.reserve(additional);
+    }
+
+    fn count_retained<F: FnMut(&T) -> bool>(&mut self, mut f: F) -> usize {
+        self.iter().filter(|val| f(val)).count()
+    }
+
+    fn retain<F: FnMut(&T) -> bool>(&mut self, f: F) {
+        self.retain(f);
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.shrink_to_fit();
+    }
+}
+
+impl<'a, T: 'a, S> Iterable<'a, T> for IndexSet<T, S> {
+    type Iter = indexmap::set::Iter<'a, T>;
+    fn iter(&'a self) -> Self::Iter {
+        IndexSet::iter(self)
+    }
+}
+
+#[cfg(feature = "std")]
+crate::test::complete_set_test!(
+    indexmap::IndexSet::<i32, std::hash::RandomState>::default(),
+    index_set_test
+);
+
+#[cfg(all(test, feature = "std"))]
+mod test {
+    use alloc::vec::Vec;
+    use std::hash::RandomState;
+
+    use indexmap::IndexSet;
+
+    use crate::{LinearSizedCollection, SizeRangeError, SizeRestricted};
+
+    type Set = IndexSet<i32, RandomState>;
+
+    #[test]
+    fn insertion_order() {
+        let mut set = SizeRestricted::<i32, Set, 0, 4>::new(Set::default()).unwrap();
+        for val in [3, 1, 2] {
+            set.push(val).unwrap();
+        }
+        assert_eq!(set.iter().copied().collect::<Vec<_>>(), [3, 1, 2]);
+        assert_eq!(set.pop(), Some(2));
+        assert_eq!(set.last(), Some(&1));
+    }
+
+    #[test]
+    fn push_duplicate() {
+        let mut set = SizeRestricted::<i32, Set, 0, 2>::new(Set::from_iter([1])).unwrap();
+        set.push(1).unwrap();
+        assert_eq!(set.len(), 1);
+
+        set.push(2).unwrap();
+        assert_eq!(set.push(2), Ok(()));
+        assert_eq!(set.push(3), Err((SizeRangeError::TooLarge, 3)));
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn shrink_and_fill() {
+        let mut set = Set::from_iter(0..10);
+        LinearSizedCollection::shrink_to(&mut set, 4);
+        assert_eq!(set, Set::from_iter(0..4));
+
+        let mut next = 10;
+        LinearSizedCollection::extend_to_with(&mut set, 6, || {
+            next += 1;
+            next
+        });
+        assert_eq!(
+            set.iter().copied().collect::<Vec<_>>(),
+            [0, 1, 2, 3, 11, 12]
+        );
+
+        let set = SizeRestricted::<i32, Set, 2, 4>::new_fit(Set::from_iter(0..8));
+        assert_eq!(set.inner(), &Set::from_iter(0..2));
+    }
+
+    #[test]
+    fn retain_mut_merging() {
+        let mut set = SizeRestricted::<i32, Set, 3, 10>::new(Set::from_iter([1, 2, 3])).unwrap();
+        let result = set.retain_mut(|val| {
+            *val = 0;
+            true
+        });
+        assert_eq!(result, Err(SizeRangeError::TooSmall));
+        assert_eq!(set.iter().copied().collect::<Vec<_>>(), [1, 2, 3]);
+
+        let mut set = SizeRestricted::<i32, Set, 2, 10>::new(Set::from_iter([5, 1, 4, 2])).unwrap();
+        set.retain_mut(|val| {
+            *val %= 2;
+            true
+        })
+        .unwrap();
+        assert_eq!(set.iter().copied().collect::<Vec<_>>(), [1, 0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Values used to fill an IndexSet have to be unique")]
+    fn fill_duplicate() {
+        let _set = SizeRestricted::<i32, Set, 2, 4>::new_fit(Set::default());
+    }
+}
@@ -0,0 +1,229 @@
+//! Implementations of [`LinearSizedCollection`] for std types
+
+use std::{
+    collections::HashSet,
+    hash::{BuildHasher, Hash},
+};
+
+use crate::{LinearSizedCollection, ReserveError};
+
+/// A [`HashSet`] has no order, [`push`](LinearSizedCollection::push) inserts the element and
+/// [`pop`](LinearSizedCollection::pop) removes an arbitrary element.
+///
+/// Pushing an element which is already in the set doesn't insert it again, the length stays the same.
+/// [`SizeRestricted::push`](crate::SizeRestricted::push) succeeds without growing the collection in this case, even
+/// if it is already at `MAX`. Filling a set up to a length (e.g. in
+/// [`SizeRestricted::new_fit`](crate::SizeRestricted::new_fit)) panics if a fill value is already in the set.
+///
+/// Mutating elements of a set could change their hash so it doesn't implement [`ViewMut`](crate::ViewMut).
+impl<T: Hash + Eq, S: BuildHasher> LinearSizedCollection<T> for HashSet<T, S> {
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn push(&mut self, val: T) {
+        self.insert(val);
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        self.extract_if(|_| true).next()
+    }
+
+    fn grows_on_push(&self, val: &T) -> bool {
+        !self.contains(val)
+    }
+
+    fn shrink_to(&mut self, len: usize) {
+        let remove = self.len().saturating_sub(len);
+        self.extract_if(|_| true).take(remove).for_each(drop);
+    }
+
+    /// # Panics
+    ///
+    /// Panics if a value returned by `fill` is already in the set as the set wouldn't grow.
+    fn extend_to_with<F: FnMut() -> T>(&mut self, len: usize, mut fill: F) {
+        self.reserve(len.saturating_sub(self.len()));
+        while self.len() < len {
+            assert!(
+                self.insert(fill()),
+                "Values used to fill a HashSet have to be unique"
+            );
+        }
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.reserve(additional);
+    }
+
+    fn try_reserve(&mut self, additional: usize) -> Result<(), ReserveError> {
+        self.try_reserve(additional).map_err(|_| ReserveError)
+    }
+
+    fn count_retained<F: FnMut(&T) -> bool>(&mut self, mut f: F) -> usize {
+        self.iter().filter(|val| f(val)).count()
+    }
+
+    fn retain<F: FnMut(&T) -> bool>(&mut self, f: F) {
+        self.retain(f);
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.shrink_to_fit();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::{BTreeSet, HashSet};
+
+    use crate::{LinearSizedCollection, SizeRangeError, SizeRestricted};
+
+    crate::test::complete_set_test!(std::collections::HashSet::new(), hash_set_test);
+
+    #[test]
+    fn hash_set_push_pop() {
+        let mut set = SizeRestricted::<i32, HashSet<_>, 0, 3>::new(HashSet::new()).unwrap();
+        for val in 0..3 {
+            set.push(val).unwrap();
+        }
+        assert_eq!(set.push(3), Err((SizeRangeError::TooLarge, 3)));
+
+        let popped = set.pop().unwrap();
+        assert!((0..3).contains(&popped));
+        assert!(!set.inner().contains(&popped));
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn push_duplicate_at_max() {
+        let mut set = SizeRestricted::<i32, HashSet<_>, 0, 2>::new(HashSet::from([1, 2])).unwrap();
+        assert_eq!(set.push(1), Ok(()));
+        assert_eq!(set.len(), 2);
+        assert_eq!(set.push(3), Err((SizeRangeError::TooLarge, 3)));
+        assert_eq!(set.try_push(2), Ok(()));
+
+        let mut set = SizeRestricted::<i32, BTreeSet<_>, 0, 1>::new(BTreeSet::from([1])).unwrap();
+        assert_eq!(set.push(1), Ok(()));
+        assert_eq!(set.len(), 1);
+
+        let mut set = SizeRestricted::<i32, HashSet<_>, 0, 3>::new(HashSet::from([1, 2])).unwrap();
+        set.push(1).unwrap();
+        assert_eq!(set.len(), 2);
+        set.push(3).unwrap();
+        assert_eq!(set.len(), 3);
+    }
+
+    #[test]
+    fn btree_set_push_pop() {
+        let mut set = SizeRestricted::<i32, BTreeSet<_>, 1, 4>::new(BTreeSet::from([5])).unwrap();
+        set.push(2).unwrap();
+        set.push(9).unwrap();
+        set.push(2).unwrap();
+        assert_eq!(set.len(), 3);
+        assert_eq!(set.iter().copied().collect::<Vec<_>>(), [2, 5, 9]);
+
+        assert_eq!(set.pop(), Some(9));
+        assert_eq!(set.last(), Some(&5));
+        assert_eq!(set.pop(), Some(5));
+        assert_eq!(set.pop(), None);
+    }
+
+    #[test]
+    fn shrink_and_fill() {
+        let mut set = HashSet::<i32>::from_iter(0..10);
+        LinearSizedCollection::shrink_to(&mut set, 4);
+        assert_eq!(set.len(), 4);
+
+        let mut next = 10;
+        LinearSizedCollection::extend_to_with(&mut set, 6, || {
+            next += 1;
+            next
+        });
+        assert_eq!(set.len(), 6);
+
+        let mut set = BTreeSet::from_iter(0..10);
+        LinearSizedCollection::shrink_to(&mut set, 4);
+        assert_eq!(set, BTreeSet::from_iter(0..4));
+        LinearSizedCollection::extend_to_with(&mut set, 6, || {
+            next += 1;
+            next
+        });
+        assert_eq!(set, BTreeSet::from([0, 1, 2, 3, 13, 14]));
+    }
+
+    #[test]
+    fn retain() {
+        let mut set =
+            SizeRestricted::<i32, HashSet<_>, 2, 10>::new(HashSet::from_iter(0..6)).unwrap();
+        set.retain(|val| val % 2 == 0).unwrap();
+        assert_eq!(set.inner(), &HashSet::from([0, 2, 4]));
+        assert_eq!(set.retain(|val| *val == 0), Err(SizeRangeError::TooSmall));
+
+        let mut set =
+            SizeRestricted::<i32, BTreeSet<_>, 2, 10>::new(BTreeSet::from_iter(0..6)).unwrap();
+        set.retain(|val| val % 3 == 0).unwrap();
+        assert_eq!(set.inner(), &BTreeSet::from([0, 3]));
+    }
+
+    #[test]
+    fn retain_mut_merging() {
+        let mut set =
+            SizeRestricted::<i32, BTreeSet<_>, 3, 10>::new(BTreeSet::from([1, 2, 3])).unwrap();
+        let result = set.retain_mut(|val| {
+            *val = 0;
+            true
+        });
+        assert_eq!(result, Err(SizeRangeError::TooSmall));
+        assert_eq!(set.inner(), &BTreeSet::from([1, 2, 3]));
+
+        set.retain_mut(|val| {
+            *val *= 2;
+            true
+        })
+        .unwrap();
+        assert_eq!(set.inner(), &BTreeSet::from([2, 4, 6]));
+
+        let mut set =
+            SizeRestricted::<i32, HashSet<_>, 2, 10>::new(HashSet::from([1, 2, 3, 4])).unwrap();
+        let result = set.retain_mut(|val| {
+            *val %= 1;
+            true
+        });
+        assert_eq!(result, Err(SizeRangeError::TooSmall));
+        assert_eq!(set.inner(), &HashSet::from([1, 2, 3, 4]));
+
+        set.retain_mut(|val| {
+            *val %= 2;
+            true
+        })
+        .unwrap();
+        assert_eq!(set.inner(), &HashSet::from([0, 1]));
+    }
+
+    #[test]
+    fn from_fn_merging() {
+        assert_eq!(
+            SizeRestricted::<i32, BTreeSet<_>, 2, 5>::from_fn(3, |_| 0),
+            Err(SizeRangeError::TooSmall)
+        );
+        let set = SizeRestricted::<i32, BTreeSet<_>, 2, 5>::from_fn(3, |i| i as i32 % 2).unwrap();
+        assert_eq!(set.inner(), &BTreeSet::from([0, 1]));
+
+        assert_eq!(
+            SizeRestricted::<i32, HashSet<_>, 1, 5>::from_fn(0, |_| 0),
+            Err(SizeRangeError::TooSmall)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Values used to fill a HashSet have to be unique")]
+    fn fill_duplicate() {
+        let _set = SizeRestricted::<i32, HashSet<_>, 2, 4>::new_fit(HashSet::new());
+    }
+
+    #[test]
+    #[should_panic(expected = "Values used to fill a BTreeSet have to be unique")]
+    fn fill_duplicate_btree() {
+        let _set = SizeRestricted::<i32, BTreeSet<_>, 2, 4>::new_fit(BTreeSet::new());
+    }
+}
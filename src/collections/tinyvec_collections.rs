@@ -0,0 +1,211 @@
+//! Implementations of [`LinearSizedCollection`] for [`tinyvec`] types
+
+use core::{cmp::Ordering, ops::DerefMut};
+
+use tinyvec::{Array, ArrayVec};
+
+use crate::{
+    BinarySearch, FixedCapacity, Iterable, IterableMut, LinearSizedCollection, ReserveError,
+    ViewMut,
+};
+
+/// An [`ArrayVec`] can't grow beyond [`Array::CAPACITY`] so [`push`](LinearSizedCollection::push) panics if the
+/// [`ArrayVec`] is full. A [`SizeRestricted`](crate::SizeRestricted) with a `MAX` of at most [`Array::CAPACITY`] never
+/// pushes into a full [`ArrayVec`]. [`try_reserve`](LinearSizedCollection::try_reserve) fails if the space exceeds the
+/// capacity.
+impl<T, A: Array<Item = T>> LinearSizedCollection<T> for ArrayVec<A> {
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        self.pop()
+    }
+
+    fn push(&mut self, val: T) {
+        self.push(val);
+    }
+
+    fn shrink_to(&mut self, len: usize) {
+        self.truncate(len);
+    }
+
+    fn reserve(&mut self, _additional: usize) {}
+
+    fn try_reserve(&mut self, additional: usize) -> Result<(), ReserveError> {
+        if additional <= self.capacity() - self.len() {
+            Ok(())
+        } else {
+            Err(ReserveError)
+        }
+    }
+
+    fn swap_remove(&mut self, index: usize) -> Option<T> {
+        (index < self.len()).then(|| self.swap_remove(index))
+    }
+
+    fn count_retained<F: FnMut(&T) -> bool>(&mut self, mut f: F) -> usize {
+        self.as_slice().iter().filter(|val| f(val)).count()
+    }
+
+    fn retain<F: FnMut(&T) -> bool>(&mut self, f: F) {
+        self.retain(f);
+    }
+
+    fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, f: F) {
+        let retained = super::retain_mut_in_place(self.as_mut_slice(), f);
+        self.truncate(retained);
+    }
+}
+
+impl<A: Array> FixedCapacity for ArrayVec<A> {
+    const CAPACITY: usize = A::CAPACITY;
+}
+
+// SAFETY: A slice can't mutate its containers length
+unsafe impl<'a, T: 'a, A: Array<Item = T> + 'a> ViewMut<'a> for ArrayVec<A> {
+    type MutableView = &'a mut [T];
+    fn view_mut(&'a mut self) -> Self::MutableView {
+        self.deref_mut()
+    }
+}
+
+impl<'a, T: 'a, A: Array<Item = T> + 'a> Iterable<'a, T> for ArrayVec<A> {
+    type Iter = core::slice::Iter<'a, T>;
+    fn iter(&'a self) -> Self::Iter {
+        self.as_slice().iter()
+    }
+}
+
+// SAFETY: Iterating over mutable references can't mutate the length
+unsafe impl<'a, T: 'a, A: Array<Item = T> + 'a> IterableMut<'a, T> for ArrayVec<A> {
+    type IterMut = core::slice::IterMut<'a, T>;
+    fn iter_mut(&'a mut self) -> Self::IterMut {
+        self.as_mut_slice().iter_mut()
+    }
+}
+
+impl<T, A: Array<Item = T>> BinarySearch<T> for ArrayVec<A> {
+    fn binary_search_by<F: FnMut(&T) -> Ordering>(&self, f: F) -> Result<usize, usize> {
+        self.as_slice().binary_search_by(f)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, A: Array<Item = T>> LinearSizedCollection<T> for tinyvec::TinyVec<A> {
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        self.pop()
+    }
+
+    fn push(&mut self, val: T) {
+        self.push(val);
+    }
+
+    fn shrink_to(&mut self, len: usize) {
+        self.truncate(len);
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.reserve(additional);
+    }
+
+    fn reserve_exact(&mut self, additional: usize) {
+        self.reserve_exact(additional);
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.shrink_to_fit();
+    }
+
+    fn swap_remove(&mut self, index: usize) -> Option<T> {
+        (index < self.len()).then(|| self.swap_remove(index))
+    }
+
+    fn count_retained<F: FnMut(&T) -> bool>(&mut self, mut f: F) -> usize {
+        self.as_slice().iter().filter(|val| f(val)).count()
+    }
+
+    fn retain<F: FnMut(&T) -> bool>(&mut self, f: F) {
+        self.retain(f);
+    }
+}
+
+#[cfg(feature = "alloc")]
+// SAFETY: A slice can't mutate its containers length
+unsafe impl<'a, T: 'a, A: Array<Item = T> + 'a> ViewMut<'a> for tinyvec::TinyVec<A> {
+    type MutableView = &'a mut [T];
+    fn view_mut(&'a mut self) -> Self::MutableView {
+        self.deref_mut()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T: 'a, A: Array<Item = T> + 'a> Iterable<'a, T> for tinyvec::TinyVec<A> {
+    type Iter = core::slice::Iter<'a, T>;
+    fn iter(&'a self) -> Self::Iter {
+        self.as_slice().iter()
+    }
+}
+
+#[cfg(feature = "alloc")]
+// SAFETY: Iterating over mutable references can't mutate the length
+unsafe impl<'a, T: 'a, A: Array<Item = T> + 'a> IterableMut<'a, T> for tinyvec::TinyVec<A> {
+    type IterMut = core::slice::IterMut<'a, T>;
+    fn iter_mut(&'a mut self) -> Self::IterMut {
+        self.as_mut_slice().iter_mut()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, A: Array<Item = T>> BinarySearch<T> for tinyvec::TinyVec<A> {
+    fn binary_search_by<F: FnMut(&T) -> Ordering>(&self, f: F) -> Result<usize, usize> {
+        self.as_slice().binary_search_by(f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    mod linear_tinyvec_collection_test {
+        #[cfg(feature = "alloc")]
+        crate::test::complete_test!(tinyvec::TinyVec::<[i32; 16]>::new(), tinyvec_test);
+        #[cfg(feature = "alloc")]
+        crate::test::iterable_test!(tinyvec::TinyVec::<[i32; 16]>::new(), tinyvec_iterable_test);
+    }
+
+    #[test]
+    fn arrayvec_restricted_to_capacity() {
+        use crate::SizeRestricted;
+
+        let mut collection =
+            SizeRestricted::<i32, _, 0, 4>::new(tinyvec::ArrayVec::<[i32; 4]>::new()).unwrap();
+        for i in 0..4 {
+            collection.push(i).unwrap();
+        }
+        assert!(collection.push(4).is_err());
+        assert_eq!(collection.view_mut(), &mut [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn arrayvec_capacity() {
+        type Restricted = crate::SizeRestricted<i32, tinyvec::ArrayVec<[i32; 4]>, 0, 8>;
+        const CAPACITY: usize = Restricted::CAPACITY;
+        assert_eq!(CAPACITY, 4);
+        assert_eq!(Restricted::max_capacity(), 4);
+    }
+
+    #[test]
+    fn arrayvec_try_push_beyond_capacity() {
+        use crate::{SizeRestricted, TryPushError};
+
+        let mut collection =
+            SizeRestricted::<i32, _, 0, 8>::new(tinyvec::ArrayVec::<[i32; 2]>::new()).unwrap();
+        collection.try_push(0).unwrap();
+        collection.try_push(1).unwrap();
+        assert_eq!(collection.try_push(2), Err(TryPushError::AllocFailed(2)));
+        assert_eq!(collection.len(), 2);
+    }
+}
@@ -0,0 +1,132 @@
+//! A clone on write smart pointer for [`SizeRestricted`] collections
+
+use core::ops::Deref;
+
+use crate::{LinearSizedCollection, SizeRestricted};
+
+/// A clone on write [`SizeRestricted`] collection which is either borrowed or owned, this mirrors
+/// [`Cow`](alloc::borrow::Cow) for size restricted collections.
+#[derive(Debug)]
+pub enum RestrictedCow<'a, T, C: LinearSizedCollection<T>, const MIN: usize, const MAX: usize> {
+    /// A borrowed size restricted collection
+    Borrowed(&'a SizeRestricted<T, C, MIN, MAX>),
+    /// An owned size restricted collection
+    Owned(SizeRestricted<T, C, MIN, MAX>),
+}
+
+impl<'a, T, C: LinearSizedCollection<T>, const MIN: usize, const MAX: usize>
+    RestrictedCow<'a, T, C, MIN, MAX>
+{
+    /// Check wether the collection is borrowed
+    pub const fn is_borrowed(&self) -> bool {
+        matches!(self, Self::Borrowed(_))
+    }
+
+    /// Check wether the collection is owned
+    pub const fn is_owned(&self) -> bool {
+        matches!(self, Self::Owned(_))
+    }
+
+    /// Get a mutable reference to the owned collection, a borrowed collection is cloned first.
+    pub fn to_mut(&mut self) -> &mut SizeRestricted<T, C, MIN, MAX>
+    where
+        C: Clone,
+    {
+        if let Self::Borrowed(borrowed) = *self {
+            *self = Self::Owned(borrowed.clone());
+        }
+
+        match self {
+            Self::Borrowed(_) => unreachable!("A borrowed collection was replaced by an owned one"),
+            Self::Owned(owned) => owned,
+        }
+    }
+
+    /// Extract the owned collection, a borrowed collection is cloned.
+    pub fn into_owned(self) -> SizeRestricted<T, C, MIN, MAX>
+    where
+        C: Clone,
+    {
+        match self {
+            Self::Borrowed(borrowed) => borrowed.clone(),
+            Self::Owned(owned) => owned,
+        }
+    }
+}
+
+impl<T, C: LinearSizedCollection<T>, const MIN: usize, const MAX: usize> Deref
+    for RestrictedCow<'_, T, C, MIN, MAX>
+{
+    type Target = SizeRestricted<T, C, MIN, MAX>;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Self::Borrowed(borrowed) => borrowed,
+            Self::Owned(owned) => owned,
+        }
+    }
+}
+
+impl<T, C: LinearSizedCollection<T> + Clone, const MIN: usize, const MAX: usize> Clone
+    for RestrictedCow<'_, T, C, MIN, MAX>
+{
+    fn clone(&self) -> Self {
+        match self {
+            Self::Borrowed(borrowed) => Self::Borrowed(borrowed),
+            Self::Owned(owned) => Self::Owned(owned.clone()),
+        }
+    }
+}
+
+impl<'a, T, C: LinearSizedCollection<T>, const MIN: usize, const MAX: usize>
+    From<&'a SizeRestricted<T, C, MIN, MAX>> for RestrictedCow<'a, T, C, MIN, MAX>
+{
+    fn from(borrowed: &'a SizeRestricted<T, C, MIN, MAX>) -> Self {
+        Self::Borrowed(borrowed)
+    }
+}
+
+impl<T, C: LinearSizedCollection<T>, const MIN: usize, const MAX: usize>
+    From<SizeRestricted<T, C, MIN, MAX>> for RestrictedCow<'_, T, C, MIN, MAX>
+{
+    fn from(owned: SizeRestricted<T, C, MIN, MAX>) -> Self {
+        Self::Owned(owned)
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod test {
+    use alloc::{vec, vec::Vec};
+
+    use crate::{NonEmpty, RestrictedCow};
+
+    #[test]
+    fn borrowed() {
+        let collection = NonEmpty::<i32, Vec<_>>::new(vec![1, 2, 3]).unwrap();
+        let cow = RestrictedCow::from(&collection);
+        assert!(cow.is_borrowed());
+        assert_eq!(cow.len(), 3);
+
+        let owned = cow.into_owned();
+        assert_eq!(owned, collection);
+    }
+
+    #[test]
+    fn owned() {
+        let collection = NonEmpty::<i32, Vec<_>>::new(vec![1, 2, 3]).unwrap();
+        let mut cow = RestrictedCow::from(collection.clone());
+        assert!(cow.is_owned());
+        cow.to_mut().push(4).unwrap();
+        assert_eq!(cow.into_owned().inner(), &vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn to_mut_clones_borrowed() {
+        let collection = NonEmpty::<i32, Vec<_>>::new(vec![1, 2]).unwrap();
+        let mut cow = RestrictedCow::Borrowed(&collection);
+        cow.to_mut().push(3).unwrap();
+        assert!(cow.is_owned());
+        assert_eq!(cow.inner(), &vec![1, 2, 3]);
+        assert_eq!(collection.inner(), &vec![1, 2]);
+    }
+}
@@ -8,21 +8,68 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(any(feature = "alloc", test))]
 extern crate alloc;
 
 pub mod test;
 
+mod builder;
 mod collections;
+mod cow;
+mod restricted_slice;
+mod ring;
+#[cfg(feature = "impl_serde")]
+pub mod serde_with;
 
+pub use builder::*;
 pub use collections::*;
+pub use cow::*;
+pub use restricted_slice::*;
+pub use ring::*;
 
-use core::{marker::PhantomData, ops::Deref};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::{
+    cmp::Ordering,
+    marker::PhantomData,
+    ops::{ControlFlow, Deref, DerefMut, Range},
+};
 
 /// A never empty linear sized collection
 pub type NonEmpty<T, C> = SizeRestricted<T, C, 1, { usize::MAX }>;
+/// A never empty [`String`](alloc::string::String), the length is the number of chars
+#[cfg(feature = "alloc")]
+pub type NonEmptyString = NonEmpty<char, alloc::string::String>;
 /// A collection which has an exact amount of elements which can't change
 pub type ExactSized<T, C, const SIZE: usize> = SizeRestricted<T, C, SIZE, SIZE>;
 
+/// Assert at compile time that a [`SizeRestricted`] type has the expected bounds, this catches mistakes when
+/// changing type aliases.
+///
+/// ```
+/// # use srestricted::{assert_bounds, InlineVec, NonEmpty, SizeRestricted};
+/// type Username = SizeRestricted<u8, InlineVec<u8, 16>, 3, 16>;
+/// assert_bounds!(Username, min = 3, max = 16);
+/// assert_bounds!(NonEmpty<u8, InlineVec<u8, 16>>, min = 1, max = usize::MAX);
+/// ```
+///
+/// Mismatched bounds fail to compile:
+///
+/// ```compile_fail
+/// # use srestricted::{assert_bounds, InlineVec, SizeRestricted};
+/// type Username = SizeRestricted<u8, InlineVec<u8, 16>, 3, 16>;
+/// assert_bounds!(Username, min = 1, max = 16);
+/// ```
+#[macro_export]
+macro_rules! assert_bounds {
+    ($ty:ty, min = $min:expr, max = $max:expr) => {
+        const _: () = ::core::assert!(
+            <$ty>::MIN == $min && <$ty>::MAX == $max,
+            "The bounds of the SizeRestricted type don't match"
+        );
+    };
+}
+
 /// A trait for linear collections which have a determinable size at any given point in time.
 ///
 /// [`LinearSizedCollection`] reflects the general implementation of collections like [`Vec`](alloc::vec::Vec)
@@ -36,9 +83,21 @@ pub trait LinearSizedCollection<T> {
     fn len(&self) -> usize;
     /// Push element `val` to the end of the collection. Using [`pop`](LinearSizedCollection::pop) after [`push`](LinearSizedCollection::push)
     /// should return val. <br/> This behavior is important for coherent behavior of the trait and is tested inside the test module.
+    ///
+    /// A push normally increases the [`len`](LinearSizedCollection::len) by one. Collections which deduplicate their
+    /// elements (like sets) may leave the len unchanged if an equal element is already contained, those have to
+    /// override [`grows_on_push`](LinearSizedCollection::grows_on_push) and
+    /// [`extend_to_with`](LinearSizedCollection::extend_to_with) which relies on every push growing the collection.
     fn push(&mut self, val: T);
     /// Pop one element from the end of the collection. If the collection is empty [`None`](core::option::Option::None) should be returned.
     fn pop(&mut self) -> Option<T>;
+    /// Check wether [`push`](LinearSizedCollection::push)ing `val` would increase the len of the collection.
+    ///
+    /// By default this returns `true`, collections which deduplicate their elements have to return `false` if an
+    /// equal element is already contained. [`SizeRestricted::push`] accepts such pushes even if the collection is full.
+    fn grows_on_push(&self, _val: &T) -> bool {
+        true
+    }
     /// Shrink this collection to len. By default this behavior is implemented using consecutive calls to [`pop`](LinearSizedCollection::pop)
     fn shrink_to(&mut self, len: usize) {
         for _ in len..self.len() {
@@ -55,6 +114,8 @@ pub trait LinearSizedCollection<T> {
         self.extend_to_with(len, || val.clone());
     }
     /// Extends the linear collection to `len` by consecutively calling `fill` for every filled value. The values should be appended to the end.
+    /// The collection has to hold `len` elements afterwards, collections which deduplicate their elements should panic
+    /// if a filled value is already contained.
     ///
     /// Fill is called for every added element, this means you can create generator like closures.
     /// You should use [`extend_to`](LinearSizedCollection::extend_to) if the value is always the same and expensive to compute but not to clone.
@@ -73,14 +134,212 @@ pub trait LinearSizedCollection<T> {
     ///
     /// # Panics
     ///
-    /// This function should panic if the inner implementation panics (a function `try_reserve` will be added as
-    /// soon as the alloc try reserve api) is stabilised.
+    /// This function should panic if the inner implementation panics, use [`try_reserve`](LinearSizedCollection::try_reserve)
+    /// to handle allocation failures.
     fn reserve(&mut self, additional: usize);
 
+    /// Try to reserve more space for at least additional more elements like [`reserve`](LinearSizedCollection::reserve)
+    /// but return an error instead of panicking or aborting if the allocation fails.
+    ///
+    /// By default this calls [`reserve`](LinearSizedCollection::reserve) and never fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ReserveError`] if the space couldn't be allocated.
+    fn try_reserve(&mut self, additional: usize) -> Result<(), ReserveError> {
+        self.reserve(additional);
+        Ok(())
+    }
+
+    /// Reserve space for exactly `additional` more elements without speculative over-allocation, see
+    /// [`Vec::reserve_exact`](alloc::vec::Vec::reserve_exact).
+    ///
+    /// By default this calls [`reserve`](LinearSizedCollection::reserve).
+    fn reserve_exact(&mut self, additional: usize) {
+        self.reserve(additional);
+    }
+
+    /// Set the length of the collection to `len`. Collections which can hold uninitialized elements like
+    /// [`Vec`](alloc::vec::Vec) and [`InlineVec`] override this to neither initialize nor drop any element, like
+    /// [`Vec::set_len`](alloc::vec::Vec::set_len).
+    ///
+    /// The default implementation can only shrink the collection and **drops** the removed elements with
+    /// [`shrink_to`](LinearSizedCollection::shrink_to).
+    ///
+    /// # Panics
+    ///
+    /// The default implementation panics if `len` is larger than the current length.
+    ///
+    /// # Safety
+    ///
+    /// `len` must not exceed the capacity of the collection and the elements in `0..len` must be initialized.
+    /// Callers which don't know the implementation must not rely on the elements after `len` being dropped or not.
+    unsafe fn set_len(&mut self, len: usize) {
+        assert!(
+            len <= self.len(),
+            "This collection can only shrink with set_len"
+        );
+        self.shrink_to(len);
+    }
+
     /// Check wether this [`LinearSizedCollection`] is empty
     fn is_empty(&self) -> bool {
         self.len() == 0
     }
+
+    /// Count the elements for which `f` returns `true`, this has to be the length of the collection after calling
+    /// [`retain`](LinearSizedCollection::retain) with the same `f`. `f` has to be called with the elements in the
+    /// same order as by [`retain`](LinearSizedCollection::retain) and the collection has to be unchanged afterwards.
+    ///
+    /// By default this is implemented by popping all elements and pushing them back, collections which can be
+    /// iterated should override this.
+    fn count_retained<F: FnMut(&T) -> bool>(&mut self, mut f: F) -> usize
+    where
+        Self: Sized,
+    {
+        let mut retained = 0;
+        let len = self.len();
+        collections::pop_and_replay(self, len, |collection, val| {
+            if f(&val) {
+                retained += 1;
+            }
+            collection.push(val);
+        });
+        retained
+    }
+
+    /// Retain only the elements for which `f` returns `true`. `f` is called for every element in order from the
+    /// front to the back and the order of the retained elements is kept.
+    ///
+    /// By default this uses [`retain_mut`](LinearSizedCollection::retain_mut).
+    fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F)
+    where
+        Self: Sized,
+    {
+        self.retain_mut(|val| f(val));
+    }
+
+    /// Retain only the elements for which `f` returns `true`, `f` may mutate the elements it is called with.
+    /// `f` is called for every element in order from the front to the back and the order of the retained elements is kept.
+    ///
+    /// By default this is implemented by popping all elements and pushing the retained elements back.
+    fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, mut f: F)
+    where
+        Self: Sized,
+    {
+        let len = self.len();
+        collections::pop_and_replay(self, len, |collection, mut val| {
+            if f(&mut val) {
+                collection.push(val);
+            }
+        });
+    }
+
+    /// Remove all elements for which `f` returns `true` and return them, `f` may mutate the elements it is called with.
+    /// `f` is called for every element in order from the front to the back and the order of the remaining and the
+    /// extracted elements is kept.
+    ///
+    /// By default this is implemented by popping all elements and pushing the remaining elements back.
+    #[cfg(feature = "alloc")]
+    fn extract_if<F: FnMut(&mut T) -> bool>(&mut self, mut f: F) -> Vec<T>
+    where
+        Self: Sized,
+    {
+        let mut extracted = Vec::new();
+        let len = self.len();
+        collections::pop_and_replay(self, len, |collection, mut val| {
+            if f(&mut val) {
+                extracted.push(val);
+            } else {
+                collection.push(val);
+            }
+        });
+        extracted
+    }
+
+    /// Mark which elements remain after removing every element for which `same` returns `true`, `same` is called
+    /// with the last remaining element and the following element in order from the front to the back. The returned
+    /// marks are in the same order as the elements and the collection has to be unchanged afterwards.
+    ///
+    /// By default this is implemented by popping all elements and pushing them back.
+    #[cfg(feature = "alloc")]
+    fn mark_deduped<F: FnMut(&T, &T) -> bool>(&mut self, same: F) -> Vec<bool>
+    where
+        Self: Sized,
+    {
+        let mut elements = Vec::with_capacity(self.len());
+        let len = self.len();
+        collections::pop_and_replay(self, len, |_, val| elements.push(val));
+        let remaining = collections::mark_deduped_in_slice(&elements, same);
+        for val in elements {
+            self.push(val);
+        }
+
+        remaining
+    }
+
+    /// Append clones of all elements in `other` to the end of the collection.
+    /// By default this reserves space for `other` and pushes every element.
+    fn extend_from_slice(&mut self, other: &[T])
+    where
+        T: Clone,
+    {
+        self.reserve(other.len());
+        for val in other {
+            self.push(val.clone());
+        }
+    }
+
+    /// Shrink the capacity of this collection as much as possible. This must not change the
+    /// [`len`](LinearSizedCollection::len) of the collection.
+    /// If this collection is not a reserving/array based collection this function should silently return which is the default.
+    fn shrink_to_fit(&mut self) {}
+
+    /// Remove the element at `index` and return it. The last element of the collection is moved into its place,
+    /// this means the order of the elements is not preserved. If `index` is out of bounds [`None`] should be returned.
+    ///
+    /// By default this is implemented with [`pop`](LinearSizedCollection::pop) and [`push`](LinearSizedCollection::push)
+    /// which is O(n). Collections with random access (like [`Vec`](alloc::vec::Vec)) should override this with an O(1) implementation.
+    fn swap_remove(&mut self, index: usize) -> Option<T> {
+        let len = self.len();
+        if index >= len {
+            return None;
+        }
+
+        let mut last = self.pop();
+        if index + 1 == len {
+            return last;
+        }
+
+        let mut removed = None;
+        collections::pop_and_replay(self, len - 1 - index, |collection, val| match last.take() {
+            Some(last) => {
+                removed = Some(val);
+                collection.push(last);
+            }
+            None => collection.push(val),
+        });
+        removed
+    }
+}
+
+/// A [`LinearSizedCollection`] which can also add and remove elements at the front.
+///
+/// [`push_front`](LinearSizedDeque::push_front) and [`pop_front`](LinearSizedDeque::pop_front) have to behave like
+/// [`push`](LinearSizedCollection::push) and [`pop`](LinearSizedCollection::pop) but operate on the front of the collection.
+pub trait LinearSizedDeque<T>: LinearSizedCollection<T> {
+    /// Push element `val` to the front of the collection. Using [`pop_front`](LinearSizedDeque::pop_front) after
+    /// [`push_front`](LinearSizedDeque::push_front) should return val.
+    fn push_front(&mut self, val: T);
+    /// Pop one element from the front of the collection. If the collection is empty [`None`] should be returned.
+    fn pop_front(&mut self) -> Option<T>;
+    /// Shrink this collection to `len` by removing elements from the front. By default this behavior is implemented
+    /// using consecutive calls to [`pop_front`](LinearSizedDeque::pop_front)
+    fn truncate_front(&mut self, len: usize) {
+        for _ in len..self.len() {
+            self.pop_front();
+        }
+    }
 }
 
 /// Used to receive a mutable view into a linear collection
@@ -93,6 +352,7 @@ pub trait LinearSizedCollection<T> {
 /// Implementors of this trait must guarantee that [`MutableView`] can not mutate the length of the
 /// [`LinearSizedCollection`], certain functions may rely on the corectness of the length thus this
 /// invariant must be upheld.
+/// The same invariant applies to the iterators of [`IterableMut`].
 pub unsafe trait ViewMut<'a> {
     /// This has to be a mutable view into the Self which can NOT mutate the length of the collection it is viewing into.
     /// If this type can mutate the length it can cause undefined behavior inside [`SizeRestricted`].
@@ -102,6 +362,84 @@ pub unsafe trait ViewMut<'a> {
     fn view_mut(&'a mut self) -> Self::MutableView;
 }
 
+/// Used to iterate over references to the elements of a linear collection
+///
+/// Iterating has to yield the elements in order from the front to the back, the last element yielded is the element
+/// which would be returned by [`pop`](LinearSizedCollection::pop).
+pub trait Iterable<'a, T: 'a> {
+    /// The iterator over references to the elements of the collection, it can be reversed to iterate from the back
+    /// to the front
+    type Iter: DoubleEndedIterator<Item = &'a T>;
+    /// Create an iterator over references to all elements
+    fn iter(&'a self) -> Self::Iter;
+}
+
+/// Used to iterate over mutable references to the elements of a linear collection
+///
+/// The elements have to be yielded in the same order as [`Iterable::iter`] yields them.
+///
+/// # Safety
+///
+/// Implementors of this trait must guarantee that [`IterMut`](IterableMut::IterMut) can not mutate the length of the
+/// [`LinearSizedCollection`], like [`ViewMut`] the iterator may only allow mutating the elements. Iterators which can
+/// insert or remove elements (like a cursor into a linked list) must not be used, iterators like
+/// [`slice::IterMut`](core::slice::IterMut) or [`linked_list::IterMut`](alloc::collections::linked_list::IterMut)
+/// only hand out mutable references to the elements.
+pub unsafe trait IterableMut<'a, T: 'a> {
+    /// The iterator over mutable references to the elements of the collection, it can be reversed to iterate from
+    /// the back to the front
+    type IterMut: DoubleEndedIterator<Item = &'a mut T>;
+    /// Create an iterator over mutable references to all elements
+    fn iter_mut(&'a mut self) -> Self::IterMut;
+}
+
+/// Used to get the elements of a linear collection as two contiguous slices without rearranging it, like
+/// [`VecDeque::as_slices`](alloc::collections::VecDeque::as_slices).
+pub trait AsSlices<T> {
+    /// Get the elements as two slices, the elements of the first slice come before the elements of the second.
+    fn as_slices(&self) -> (&[T], &[T]);
+}
+
+/// A [`LinearSizedCollection`] which can never hold more than [`CAPACITY`](FixedCapacity::CAPACITY) elements,
+/// like array backed collections.
+pub trait FixedCapacity {
+    /// The static maximum number of elements the collection can hold
+    const CAPACITY: usize;
+}
+
+/// Used to search a sorted linear collection
+///
+/// Collections with random access should implement this as a binary search, other collections
+/// (like [`LinkedList`](alloc::collections::LinkedList)) may fall back to a linear search.
+pub trait BinarySearch<T> {
+    /// Search the sorted collection with the comparator function `f` which returns the ordering of the
+    /// given element relative to the searched element.
+    ///
+    /// This has to behave like [`slice::binary_search_by`]: If a matching element is found [`Ok`] with its index is returned,
+    /// otherwise [`Err`] with the index where a matching element could be inserted while maintaining the sorted order.
+    #[allow(clippy::missing_errors_doc)]
+    fn binary_search_by<F: FnMut(&T) -> Ordering>(&self, f: F) -> Result<usize, usize>;
+
+    /// Get the index of the first element for which `pred` returns `false`, the collection has to be partitioned
+    /// so that all elements for which `pred` returns `true` come first.
+    ///
+    /// This has to behave like [`slice::partition_point`], by default it is implemented with
+    /// [`binary_search_by`](BinarySearch::binary_search_by).
+    fn partition_point<F: FnMut(&T) -> bool>(&self, mut pred: F) -> usize
+    where
+        Self: Sized,
+    {
+        self.binary_search_by(|elem| {
+            if pred(elem) {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            }
+        })
+        .unwrap_or_else(|i| i)
+    }
+}
+
 /// An error representing a [`LinearSizedCollection`]s len being out of the bound of a [`SizeRestricted`]
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Ord, PartialOrd)]
 pub enum SizeRangeError {
@@ -120,9 +458,101 @@ impl core::fmt::Display for SizeRangeError {
     }
 }
 
+/// An error returned by [`SizeRestricted::from_parts`]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum FromPartsError {
+    /// The runtime bounds didn't match the bounds of the [SizeRestricted]
+    BoundsMismatch,
+    /// The collection didn't fit in the bounds
+    Size(SizeRangeError),
+}
+
+impl core::fmt::Display for FromPartsError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Self::BoundsMismatch => write!(f, "Bounds Mismatch"),
+            Self::Size(e) => e.fmt(f),
+        }
+    }
+}
+
+/// An error returned by [`SizeRestricted::select`]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum SelectError {
+    /// The index was out of bounds of the collection
+    OutOfBounds(usize),
+    /// The index was given more than once
+    Duplicate(usize),
+    /// Too few indices were given
+    Size(SizeRangeError),
+}
+
+impl core::fmt::Display for SelectError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Self::OutOfBounds(index) => write!(f, "Index {index} Out Of Bounds"),
+            Self::Duplicate(index) => write!(f, "Duplicate Index {index}"),
+            Self::Size(e) => e.fmt(f),
+        }
+    }
+}
+
+/// An error returned if a [`LinearSizedCollection`] failed to allocate space with
+/// [`try_reserve`](LinearSizedCollection::try_reserve)
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct ReserveError;
+
+impl core::fmt::Display for ReserveError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Allocation Failed")
+    }
+}
+
+/// An error returned by [`SizeRestricted::try_push`] containing the element which wasn't pushed
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum TryPushError<T> {
+    /// The collection already had [SizeRestricted]::MAX elements
+    SizeExceeded(T),
+    /// The collection failed to allocate space for the element
+    AllocFailed(T),
+}
+
+impl<T> TryPushError<T> {
+    /// Get the element which wasn't pushed
+    pub fn into_inner(self) -> T {
+        match self {
+            Self::SizeExceeded(val) | Self::AllocFailed(val) => val,
+        }
+    }
+}
+
+impl<T> core::fmt::Display for TryPushError<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::SizeExceeded(_) => write!(f, "Size Exceeded"),
+            Self::AllocFailed(_) => write!(f, "Allocation Failed"),
+        }
+    }
+}
+
+/// A report of the lengths of a collection mutated with [`SizeRestricted::mutate_reporting`]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct MutationReport {
+    /// The length before the mutator was executed
+    pub pre_len: usize,
+    /// The length after the mutator was executed
+    pub post_mutator_len: usize,
+    /// The length after the collection was made fitting
+    pub final_len: usize,
+    /// [`SizeRangeError::TooLarge`] if the collection had to be shrunk, [`SizeRangeError::TooSmall`] if it had to be
+    /// extended or [`None`] if it already fit after the mutator was executed
+    pub correction: Option<SizeRangeError>,
+}
+
 /// A wrapper around a [`LinearSizedCollection`] to restricts its size. The [`length`](LinearSizedCollection::len) is ensured
 /// to be between MIN and MAX including both MIN and MAX.
-#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Hash)]
+///
+/// Comparisons only compare the inner collections, this means collections with different bounds can be compared.
 pub struct SizeRestricted<T, C: LinearSizedCollection<T>, const MIN: usize, const MAX: usize> {
     /// The inner collection whichs size is restricted
     collection: C,
@@ -145,6 +575,14 @@ impl<T, C: LinearSizedCollection<T>, const MIN: usize, const MAX: usize>
         );
         true
     };
+    /// A check that the collection can never be empty, used by functions which rely on at least one element
+    const NON_EMPTY: bool = {
+        assert!(
+            MIN >= 1,
+            "The MIN size of a SizeRestricted must be at least 1 to never be empty"
+        );
+        true
+    };
 
     /// Create a [`SizeRestricted`] while ensuring that the given collection has a correct size.
     /// If an error occurs the collection will be returned and a [`SizeRangeError`] describing the error.
@@ -163,6 +601,21 @@ impl<T, C: LinearSizedCollection<T>, const MIN: usize, const MAX: usize>
         }
     }
 
+    /// Create a [`SizeRestricted`] while truncating the collection to `MAX` elements if it is too large.
+    /// Elements can't be invented so a collection which is too small is an error, use [`new_fit`](SizeRestricted::new_fit)
+    /// to fill it with default values.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SizeRangeError::TooSmall`] and the collection if it has less than `MIN` elements.
+    pub fn new_truncating(mut collection: C) -> Result<Self, (SizeRangeError, C)> {
+        if let Err(SizeRangeError::TooLarge) = Self::check_fit(&collection) {
+            collection.shrink_to(MAX);
+        }
+
+        Self::new(collection)
+    }
+
     /// Creates a new instance of Self while making collection fit into the restriction using [`Self::make_fit`](SizeRestricted::make_fit)
     pub fn new_fit(mut collection: C) -> Self
     where
@@ -172,6 +625,63 @@ impl<T, C: LinearSizedCollection<T>, const MIN: usize, const MAX: usize>
         Self::create(collection)
     }
 
+    /// Create a [`SizeRestricted`] with `len` elements by calling `f` with the index of every element, like
+    /// [`core::array::from_fn`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SizeRangeError::TooSmall`] or [`SizeRangeError::TooLarge`] if `len` doesn't fit the size restriction,
+    /// this is checked before any element is created. Returns [`SizeRangeError::TooSmall`] as well if the created
+    /// collection is smaller than [`Self::MIN`], e.g. because a set merged equal elements.
+    pub fn from_fn<F: FnMut(usize) -> T>(len: usize, f: F) -> Result<Self, SizeRangeError>
+    where
+        C: Default,
+    {
+        if len > MAX {
+            return Err(SizeRangeError::TooLarge);
+        } else if len < MIN {
+            return Err(SizeRangeError::TooSmall);
+        }
+
+        let mut collection = C::default();
+        collection.reserve(len);
+        for val in (0..len).map(f) {
+            collection.push(val);
+        }
+
+        Self::new(collection).map_err(|(err, _)| err)
+    }
+
+    /// Create a collection with [`Self::MIN`] elements returned by `fill`, this is like [`Default`] but doesn't
+    /// require `T` to implement [`Default`].
+    pub fn with_min_fill<F: FnMut() -> T>(fill: F) -> Self
+    where
+        C: Default,
+    {
+        let mut collection = C::default();
+        collection.extend_to_with(MIN, fill);
+        Self::create(collection)
+    }
+
+    /// Create a collection with the single element `val`, this is the most common way to create a [`NonEmpty`]
+    /// collection.
+    ///
+    /// This fails to compile if the bounds don't allow a length of 1.
+    pub fn singleton(val: T) -> Self
+    where
+        C: Default,
+    {
+        const {
+            assert!(
+                MIN <= 1 && 1 <= MAX,
+                "The bounds must allow a single element"
+            );
+        };
+        let mut collection = C::default();
+        collection.push(val);
+        Self::create(collection)
+    }
+
     #[allow(clippy::missing_errors_doc)]
     /// Returns wether the given collections size is correct. [`Ok`] will be returned if it fits, if it is too small
     /// [`SizeRangeError::TooSmall`] and if the collection is too large [`SizeRangeError::TooLarge`] will be returned.
@@ -186,6 +696,41 @@ impl<T, C: LinearSizedCollection<T>, const MIN: usize, const MAX: usize>
         }
     }
 
+    /// Returns wether a collection with `len` elements would fit the size restriction, this can be used in const context.
+    pub const fn accepts_len(len: usize) -> bool {
+        MIN <= len && len <= MAX
+    }
+
+    /// Check wether the current length fits into the runtime bounds `range`, like [`check_fit`](SizeRestricted::check_fit)
+    /// does for `MIN` and `MAX`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SizeRangeError::TooSmall`] if the length is below `range` and [`SizeRangeError::TooLarge`] if the
+    /// length is above `range`.
+    pub fn fits_range(
+        &self,
+        range: core::ops::RangeInclusive<usize>,
+    ) -> Result<(), SizeRangeError> {
+        let len = self.collection.len();
+        if len > *range.end() {
+            Err(SizeRangeError::TooLarge)
+        } else if len < *range.start() {
+            Err(SizeRangeError::TooSmall)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Create a [`SizeRestrictedBuilder`] to incrementally construct a collection which only has to fit the size restriction
+    /// when it is [`built`](SizeRestrictedBuilder::build).
+    pub fn builder() -> SizeRestrictedBuilder<T, C, MIN, MAX>
+    where
+        C: Default,
+    {
+        SizeRestrictedBuilder::new()
+    }
+
     /// Makes the given collection fit into the size restriction. Uses [`Default::default`] for extending with [`SizeRestricted::make_fit_with`]
     pub fn make_fit(collection: &mut C)
     where
@@ -196,6 +741,10 @@ impl<T, C: LinearSizedCollection<T>, const MIN: usize, const MAX: usize>
 
     /// Makes the given collection fit into the size restriction. Uses `fill` to extend the collection with [`LinearSizedCollection::extend_to_with`]
     /// if the collection is to small
+    ///
+    /// A collection which is too small is extended up to `MAX` elements and a collection which is too large is shrunk
+    /// down to `MIN` elements. Use [`make_fit_to_min_with`](SizeRestricted::make_fit_to_min_with) to change the collection
+    /// as little as possible instead.
     pub fn make_fit_with<F: FnMut() -> T>(collection: &mut C, fill: F) {
         match Self::check_fit(collection) {
             Ok(()) => {}
@@ -204,6 +753,17 @@ impl<T, C: LinearSizedCollection<T>, const MIN: usize, const MAX: usize>
         }
     }
 
+    /// Makes the given collection fit into the size restriction while changing it as little as possible.
+    /// A collection which is too small is extended with `fill` up to `MIN` elements and a collection which is too large
+    /// is shrunk down to `MAX` elements.
+    pub fn make_fit_to_min_with<F: FnMut() -> T>(collection: &mut C, fill: F) {
+        match Self::check_fit(collection) {
+            Ok(()) => {}
+            Err(SizeRangeError::TooLarge) => collection.shrink_to(MAX),
+            Err(SizeRangeError::TooSmall) => collection.extend_to_with(MIN, fill),
+        }
+    }
+
     /// Creates this `SizeRestricted` collection from the collection parameter.
     ///
     /// # Panics
@@ -222,7 +782,24 @@ impl<T, C: LinearSizedCollection<T>, const MIN: usize, const MAX: usize>
         });
         Self {
             collection,
-            _phantom: PhantomData::default(),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Creates this `SizeRestricted` collection from the collection parameter without checking its size.
+    /// Unlike [`create`](SizeRestricted::create) nothing is checked in release builds.
+    ///
+    /// # Safety
+    ///
+    /// The length of `collection` has to be in between [`Self::MIN`] and [`Self::MAX`].
+    pub unsafe fn from_inner_unchecked(collection: C) -> Self {
+        debug_assert!(
+            Self::accepts_len(collection.len()),
+            "from_inner_unchecked has to keep the size restriction"
+        );
+        Self {
+            collection,
+            _phantom: PhantomData,
         }
     }
 
@@ -231,25 +808,102 @@ impl<T, C: LinearSizedCollection<T>, const MIN: usize, const MAX: usize>
         &self.collection
     }
 
+    /// Get the [`length`](LinearSizedCollection::len) of the inner collection which is always between MIN and MAX
+    pub fn len(&self) -> usize {
+        self.collection.len()
+    }
+
+    /// Check wether the inner collection is empty. This can only be the case if MIN is 0
+    pub fn is_empty(&self) -> bool {
+        self.collection.is_empty()
+    }
+
+    /// Check that the inner collection still fits in the size restriction with [`check_fit`](SizeRestricted::check_fit).
+    ///
+    /// This can only fail if the invariant was broken, e.g. by a wrong unsafe implementation or a collection which
+    /// can change its length through shared state.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SizeRangeError`] if the inner collection doesn't fit in the size restriction
+    pub fn validate(&self) -> Result<(), SizeRangeError> {
+        Self::check_fit(&self.collection)
+    }
+
+    /// Assert that the inner collection still fits in the size restriction with [`validate`](SizeRestricted::validate),
+    /// nothing is checked in release builds.
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if the inner collection doesn't fit in the size restriction
+    pub fn debug_assert_valid(&self) {
+        debug_assert_eq!(
+            self.validate(),
+            Ok(()),
+            "The inner collection of a SizeRestricted has to fit in its size restriction"
+        );
+    }
+
     /// Mutate the inner collection directly with the `mutator` function.
     ///
     /// The size range may be violated inside the mutator function and the collection is made fitting after `mutator` got executed.
     /// The resizing of the collection is done with [`make_fit_with`](SizeRestricted::make_fit_with) using `fill` as filling function.
-    pub fn mutate(&mut self, fill: impl FnMut() -> T, mut mutator: impl FnMut(&mut C)) {
+    ///
+    /// Returns `true` if the collection had to be resized after `mutator` got executed. Use
+    /// [`mutate_silent`](SizeRestricted::mutate_silent) if this is not of interest, ignoring the result is a warning:
+    ///
+    /// ```compile_fail
+    /// #![deny(unused_must_use)]
+    /// # use srestricted::NonEmpty;
+    /// let mut collection = NonEmpty::<i32, Vec<_>>::new(vec![1]).unwrap();
+    /// collection.mutate(|| 0, |inner| inner.clear());
+    /// ```
+    #[must_use = "the collection may have been resized silently, use `mutate_silent` to ignore this"]
+    pub fn mutate(&mut self, fill: impl FnMut() -> T, mutator: impl FnMut(&mut C)) -> bool {
+        self.mutate_reporting(fill, mutator).correction.is_some()
+    }
+
+    /// Mutate the inner collection directly with the `mutator` function like [`mutate`](SizeRestricted::mutate)
+    /// and report the lengths before and after every step in a [`MutationReport`].
+    pub fn mutate_reporting(
+        &mut self,
+        fill: impl FnMut() -> T,
+        mutator: impl FnOnce(&mut C),
+    ) -> MutationReport {
+        let pre_len = self.collection.len();
         mutator(&mut self.collection);
 
+        let post_mutator_len = self.collection.len();
+        let correction = Self::check_fit(&self.collection).err();
         Self::make_fit_with(&mut self.collection, fill);
+
+        MutationReport {
+            pre_len,
+            post_mutator_len,
+            final_len: self.collection.len(),
+            correction,
+        }
+    }
+
+    /// Mutate the inner collection directly with the `mutator` function like [`mutate`](SizeRestricted::mutate)
+    /// without reporting wether the collection had to be resized.
+    pub fn mutate_silent(&mut self, fill: impl FnMut() -> T, mutator: impl FnMut(&mut C)) {
+        let _ = self.mutate(fill, mutator);
     }
 
     /// Push an element to the collections. Returns [Ok] if pushing the element doesn't violate the size restriction,
     /// returns ([`SizeRangeError::TooLarge`], val) on error
     ///
+    /// Pushing an element which doesn't increase the length (see [`LinearSizedCollection::grows_on_push`]), like an
+    /// element which is already in a set, always succeeds.
+    ///
     /// # Errors
     ///
     /// This function returns [`SizeRangeError::TooLarge`] if the size would exceed [`Self::MAX`]
     /// after the push.
+    #[must_use = "the value is returned on error and lost if the result is ignored"]
     pub fn push(&mut self, val: T) -> Result<(), (SizeRangeError, T)> {
-        if self.collection.len() == MAX {
+        if self.collection.len() >= MAX && self.collection.grows_on_push(&val) {
             Err((SizeRangeError::TooLarge, val))
         } else {
             self.collection.push(val);
@@ -257,90 +911,3037 @@ impl<T, C: LinearSizedCollection<T>, const MIN: usize, const MAX: usize>
         }
     }
 
-    /// Pops an element if the size restriction doesn't get violated by the pop.
-    pub fn pop(&mut self) -> Option<T> {
-        if self.collection.len() == MIN {
-            None
-        } else {
-            self.collection.pop()
+    /// Extend the collection to `len` elements by appending clones of `val`.
+    /// Nothing happens if the collection already has at least `len` elements.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SizeRangeError::TooLarge`] if `len` is larger than [`Self::MAX`], in this case nothing is appended.
+    #[must_use = "nothing is appended if this returns an error"]
+    pub fn grow_to(&mut self, len: usize, val: T) -> Result<(), SizeRangeError>
+    where
+        T: Clone,
+    {
+        if len > MAX {
+            return Err(SizeRangeError::TooLarge);
         }
-    }
 
-    /// Unwraps the inner collection and lifts the size restriction
-    pub fn into_inner(self) -> C {
-        self.collection
+        self.collection.extend_to(len, val);
+        Ok(())
     }
 
-    /// Get an immutable view into the collection
-    pub fn view(&self) -> &<C as Deref>::Target
+    /// Shrink the collection to `len` elements by removing elements from the end.
+    /// Nothing happens if the collection already has at most `len` elements.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SizeRangeError::TooSmall`] if `len` is smaller than [`Self::MIN`], in this case nothing is removed.
+    #[must_use = "nothing is removed if this returns an error"]
+    pub fn shrink_to_checked(&mut self, len: usize) -> Result<(), SizeRangeError> {
+        if len < MIN {
+            return Err(SizeRangeError::TooSmall);
+        }
+
+        self.collection.shrink_to(len);
+        Ok(())
+    }
+
+    /// Resize the collection to `new_len` elements, new elements are default values. See [`resize_with`](SizeRestricted::resize_with).
+    ///
+    /// Ignoring the result is a warning as the collection is not resized if `new_len` doesn't fit:
+    ///
+    /// ```compile_fail
+    /// #![deny(unused_must_use)]
+    /// # use srestricted::SizeRestricted;
+    /// let mut collection = SizeRestricted::<i32, Vec<_>, 1, 4>::new(vec![1]).unwrap();
+    /// collection.resize(8);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `new_len` is not in between [`Self::MIN`] and [`Self::MAX`], the collection is unchanged.
+    #[must_use = "the collection is not resized if this returns an error"]
+    pub fn resize(&mut self, new_len: usize) -> Result<(), SizeRangeError>
     where
-        C: Deref,
+        T: Default,
     {
-        &self.collection
+        self.resize_with(new_len, T::default)
     }
 
-    /// Get a mutable view into the collection.
+    /// Resize the collection to `new_len` elements by shrinking it or extending it with values returned by `f`.
     ///
-    /// This is implemented with the [`ViewMut`] trait refer to it for more information on safety
-    pub fn view_mut<'a>(&'a mut self) -> <C as ViewMut>::MutableView
+    /// # Errors
+    ///
+    /// Returns an error if `new_len` is not in between [`Self::MIN`] and [`Self::MAX`], the collection is unchanged.
+    #[must_use = "the collection is not resized if this returns an error"]
+    pub fn resize_with<F: FnMut() -> T>(
+        &mut self,
+        new_len: usize,
+        f: F,
+    ) -> Result<(), SizeRangeError> {
+        if new_len > MAX {
+            return Err(SizeRangeError::TooLarge);
+        } else if new_len < MIN {
+            return Err(SizeRangeError::TooSmall);
+        }
+
+        if new_len < self.collection.len() {
+            self.collection.shrink_to(new_len);
+        } else {
+            self.collection.extend_to_with(new_len, f);
+        }
+        Ok(())
+    }
+
+    /// Push an element to the collection without panicking or aborting if the allocation fails, space for the element
+    /// is reserved with [`LinearSizedCollection::try_reserve`].
+    ///
+    /// Like [`push`](SizeRestricted::push) pushing an element which doesn't increase the length never exceeds the size.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TryPushError::SizeExceeded`] if the size would exceed [`Self::MAX`] after the push and
+    /// [`TryPushError::AllocFailed`] if the space for the element couldn't be allocated.
+    #[must_use = "the value is returned on error and lost if the result is ignored"]
+    pub fn try_push(&mut self, val: T) -> Result<(), TryPushError<T>> {
+        if self.collection.len() >= MAX && self.collection.grows_on_push(&val) {
+            return Err(TryPushError::SizeExceeded(val));
+        }
+
+        match self.collection.try_reserve(1) {
+            Ok(()) => {
+                self.collection.push(val);
+                Ok(())
+            }
+            Err(ReserveError) => Err(TryPushError::AllocFailed(val)),
+        }
+    }
+
+    /// Removes the element at `index` and returns it, the last element is moved into its place.
+    /// This does not preserve the order of the elements but is O(1) for collections like [`Vec`](alloc::vec::Vec).
+    ///
+    /// Returns [`None`] without changing the collection if `index` is out of bounds or if the removal would
+    /// violate the size restriction.
+    pub fn swap_remove(&mut self, index: usize) -> Option<T> {
+        let len = self.collection.len();
+        if len <= MIN || index >= len {
+            None
+        } else {
+            self.collection.swap_remove(index)
+        }
+    }
+
+    /// Append clones of all elements in `other` to the collection.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SizeRangeError::TooLarge`] if the size would exceed [`Self::MAX`] after appending,
+    /// in this case nothing is appended.
+    #[must_use = "nothing is appended if this returns an error"]
+    pub fn extend_from_slice(&mut self, other: &[T]) -> Result<(), SizeRangeError>
     where
-        C: ViewMut<'a>,
+        T: Clone,
     {
-        self.collection.view_mut()
+        if other.len() > MAX - self.collection.len() {
+            Err(SizeRangeError::TooLarge)
+        } else {
+            self.collection.extend_from_slice(other);
+            Ok(())
+        }
     }
-}
 
-/// Creates a `SizeRestricted` collection with a size of `MIN`
-impl<T, C, const MIN: usize, const MAX: usize> Default for SizeRestricted<T, C, MIN, MAX>
-where
-    C: LinearSizedCollection<T> + Default,
-    T: Default,
-{
-    fn default() -> Self {
-        let mut collection = C::default();
-        collection.extend_to_with(MIN, Default::default);
-        Self::create(collection)
+    /// Push all elements of `iter` to the collection, either all elements are pushed or none.
+    ///
+    /// The elements are collected into a temporary [`Vec`] first to check wether they fit.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SizeRangeError::TooLarge`] and all collected elements if the size would exceed [`Self::MAX`]
+    /// after pushing them, in this case nothing is pushed.
+    #[must_use = "the values are returned on error and lost if the result is ignored"]
+    #[cfg(feature = "alloc")]
+    pub fn try_push_all<I: IntoIterator<Item = T>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), (SizeRangeError, Vec<T>)> {
+        let batch = iter.into_iter().collect::<Vec<_>>();
+        if batch.len() > MAX - self.collection.len() {
+            return Err((SizeRangeError::TooLarge, batch));
+        }
+
+        self.collection.reserve(batch.len());
+        for val in batch {
+            self.collection.push(val);
+        }
+
+        Ok(())
     }
-}
 
-impl<T, C, const MIN: usize, const MAX: usize> IntoIterator for SizeRestricted<T, C, MIN, MAX>
-where
-    C: LinearSizedCollection<T> + IntoIterator<Item = T>,
-{
-    type IntoIter = C::IntoIter;
-    type Item = T;
+    /// Push the elements of `iter` until the collection holds [`Self::MAX`] elements and return the number of elements
+    /// which didn't fit. `0` is returned if every element fit.
+    ///
+    /// The rest of the iterator is consumed to count the elements which didn't fit, this means this never returns for
+    /// infinite iterators. Limit those with [`take`](Iterator::take) first.
+    pub fn extend_saturating<I: IntoIterator<Item = T>>(&mut self, iter: I) -> usize {
+        let mut iter = iter.into_iter();
+        self.fill_from(&mut iter);
+        iter.count()
+    }
 
-    fn into_iter(self) -> Self::IntoIter {
-        self.into_inner().into_iter()
+    /// Push the elements of `iter` until the collection holds [`Self::MAX`] elements, the elements which don't fit
+    /// are left in `iter`.
+    fn fill_from<I: Iterator<Item = T>>(&mut self, iter: &mut I) {
+        let free = MAX - self.collection.len();
+        self.collection.reserve(iter.size_hint().0.min(free));
+        for val in iter.take(free) {
+            self.collection.push(val);
+        }
     }
-}
 
-#[cfg(feature = "impl_serde")]
-impl<T, C: LinearSizedCollection<T> + serde::Serialize, const MIN: usize, const MAX: usize>
-    serde::Serialize for SizeRestricted<T, C, MIN, MAX>
-{
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    /// Retain only the elements for which `f` returns `true`.
+    ///
+    /// `f` is called exactly once with every element in order from the front to the back, its results are recorded
+    /// before anything is removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SizeRangeError::TooSmall`] if less than [`Self::MIN`] elements would be retained,
+    /// in this case the collection is not changed.
+    #[must_use = "nothing is removed if this returns an error"]
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, f: F) -> Result<(), SizeRangeError> {
+        if collections::retain_at_least(&mut self.collection, MIN, f) {
+            Ok(())
+        } else {
+            Err(SizeRangeError::TooSmall)
+        }
+    }
+
+    /// Retain only the elements for which `f` returns `true`, `f` may mutate the elements it is called with.
+    ///
+    /// `f` is called exactly once with every element in order from the front to the back. The elements are cloned
+    /// before, if too few elements remain the collection is restored from the clones. This also covers sets in which
+    /// mutated elements become equal and merge.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SizeRangeError::TooSmall`] if less than [`Self::MIN`] elements would be retained,
+    /// in this case the collection is not changed.
+    #[must_use = "nothing is removed if this returns an error"]
+    #[cfg(feature = "alloc")]
+    pub fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, f: F) -> Result<(), SizeRangeError>
     where
-        S: serde::Serializer,
+        T: Clone,
     {
-        self.collection.serialize(serializer)
+        let snapshot = self.snapshot();
+        self.collection.retain_mut(f);
+        self.restore_unless_fit(snapshot)
     }
-}
 
-#[cfg(feature = "impl_serde")]
-impl<
-        'de,
-        T,
-        C: LinearSizedCollection<T> + serde::Deserialize<'de>,
-        const MIN: usize,
-        const MAX: usize,
-    > serde::Deserialize<'de> for SizeRestricted<T, C, MIN, MAX>
-{
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    /// Remove all elements for which `f` returns `true` and return them in order from the front to the back.
+    ///
+    /// `f` is called exactly once with every element in order from the front to the back. The elements are cloned
+    /// before, if too few elements remain the collection is restored from the clones.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SizeRangeError::TooSmall`] if less than [`Self::MIN`] elements would remain,
+    /// in this case the collection is not changed.
+    #[must_use = "nothing is removed if this returns an error"]
+    #[cfg(feature = "alloc")]
+    pub fn extract_if<F: FnMut(&mut T) -> bool>(&mut self, f: F) -> Result<Vec<T>, SizeRangeError>
     where
-        D: serde::Deserializer<'de>,
+        T: Clone,
     {
-        let collection = C::deserialize(deserializer)?;
-        Self::new(collection).map_err(|(e, _)| serde::de::Error::custom(e))
+        let snapshot = self.snapshot();
+        let extracted = self.collection.extract_if(f);
+        self.restore_unless_fit(snapshot).map(|()| extracted)
+    }
+
+    /// Clone all elements in order from the front to the back
+    #[cfg(feature = "alloc")]
+    fn snapshot(&mut self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        let mut snapshot = Vec::with_capacity(self.collection.len());
+        self.collection.count_retained(|val| {
+            snapshot.push(val.clone());
+            false
+        });
+        snapshot
+    }
+
+    /// Check that the collection still fits in the bounds, otherwise replace its elements with `snapshot`
+    #[cfg(feature = "alloc")]
+    fn restore_unless_fit(&mut self, snapshot: Vec<T>) -> Result<(), SizeRangeError> {
+        Self::check_fit(&self.collection).inspect_err(|_| {
+            self.collection.shrink_to(0);
+            self.collection.reserve(snapshot.len());
+            for val in snapshot {
+                self.collection.push(val);
+            }
+        })
+    }
+
+    /// Keep only the elements at the indices in `keep`, the elements are reordered to follow the order of `keep`.
+    ///
+    /// If `keep` is sorted the elements are kept in place with [`LinearSizedCollection::retain`], otherwise all
+    /// elements are popped and the selected ones are pushed back.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SelectError::OutOfBounds`] if an index is out of bounds, [`SelectError::Duplicate`] if an index is
+    /// contained in `keep` more than once and [`SelectError::Size`] if fewer than [`Self::MIN`] indices are given.
+    /// The collection is unchanged on error.
+    #[cfg(feature = "alloc")]
+    pub fn select(&mut self, keep: &[usize]) -> Result<(), SelectError> {
+        let len = self.collection.len();
+        let mut selected = alloc::vec![false; len];
+        for &index in keep {
+            match selected.get_mut(index) {
+                None => return Err(SelectError::OutOfBounds(index)),
+                Some(true) => return Err(SelectError::Duplicate(index)),
+                Some(selected) => *selected = true,
+            }
+        }
+
+        if keep.len() < MIN {
+            return Err(SelectError::Size(SizeRangeError::TooSmall));
+        }
+
+        if keep.windows(2).all(|pair| pair[0] < pair[1]) {
+            let mut selected = selected.into_iter();
+            self.collection.retain(|_| selected.next().unwrap_or(true));
+            return Ok(());
+        }
+
+        let mut elements = Vec::with_capacity(len);
+        while let Some(val) = self.collection.pop() {
+            elements.push(Some(val));
+        }
+        elements.reverse();
+
+        for &index in keep {
+            let val = elements[index]
+                .take()
+                .expect("Every index is selected only once");
+            self.collection.push(val);
+        }
+
+        Ok(())
+    }
+
+    /// Remove consecutive elements for which `same` returns true, `same` is called with the last kept element and the
+    /// following element. This keeps the order of the remaining elements.
+    ///
+    /// `same` is called exactly once for every pair, its results are recorded with
+    /// [`LinearSizedCollection::mark_deduped`] before anything is removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SizeRangeError::TooSmall`] if fewer than [`Self::MIN`] elements would remain, the collection is
+    /// unchanged on error.
+    #[cfg(feature = "alloc")]
+    pub fn dedup_consecutive<F: FnMut(&T, &T) -> bool>(
+        &mut self,
+        same: F,
+    ) -> Result<(), SizeRangeError> {
+        let remaining = self.collection.mark_deduped(same);
+        if remaining.iter().filter(|remains| **remains).count() < MIN {
+            return Err(SizeRangeError::TooSmall);
+        }
+
+        let mut remaining = remaining.into_iter();
+        self.collection.retain(|_| remaining.next().unwrap_or(true));
+        Ok(())
+    }
+
+    /// Set the length of the inner collection with [`LinearSizedCollection::set_len`]
+    ///
+    /// # Safety
+    ///
+    /// `len` has to be in between [`Self::MIN`] and [`Self::MAX`] and the safety requirements of
+    /// [`LinearSizedCollection::set_len`] have to be fulfilled.
+    pub unsafe fn set_len_unchecked(&mut self, len: usize) {
+        debug_assert!(
+            Self::accepts_len(len),
+            "set_len_unchecked has to keep the size restriction"
+        );
+        // SAFETY: Guaranteed by the caller
+        unsafe { self.collection.set_len(len) }
+    }
+
+    /// Remove elements from the front until the collection has `len` elements, `len` is clamped to [`Self::MIN`].
+    /// Nothing happens if the collection already has at most `len` elements.
+    pub fn truncate_front(&mut self, len: usize)
+    where
+        C: LinearSizedDeque<T>,
+    {
+        self.collection.truncate_front(len.max(MIN));
+    }
+
+    /// Keep only the first `n` elements, `n` is clamped to [`Self::MIN`]. Nothing happens if the collection
+    /// already has at most `n` elements.
+    pub fn keep_first(&mut self, n: usize) {
+        let len = n.max(MIN);
+        if len < self.collection.len() {
+            self.collection.shrink_to(len);
+        }
+    }
+
+    /// Keep only the last `n` elements by removing elements from the front, `n` is clamped to [`Self::MIN`].
+    /// Nothing happens if the collection already has at most `n` elements.
+    pub fn keep_last(&mut self, n: usize)
+    where
+        C: LinearSizedDeque<T>,
+    {
+        self.truncate_front(n);
+    }
+
+    /// Pops an element if the size restriction doesn't get violated by the pop.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.collection.len() <= MIN {
+            None
+        } else {
+            self.collection.pop()
+        }
+    }
+
+    /// Pop elements until the collection holds [`Self::MIN`] elements and return them in the order they were popped.
+    #[cfg(feature = "alloc")]
+    pub fn drain_to_min(&mut self) -> Vec<T> {
+        let mut drained = Vec::with_capacity(self.collection.len().saturating_sub(MIN));
+        while let Some(val) = self.pop() {
+            drained.push(val);
+        }
+        drained
+    }
+
+    /// Reserve space for exactly `additional` more elements with [`LinearSizedCollection::reserve_exact`].
+    /// `additional` is clamped so that no space is reserved for more than [`Self::MAX`] elements.
+    pub fn reserve_exact(&mut self, additional: usize) {
+        let additional = additional.min(MAX - self.collection.len());
+        self.collection.reserve_exact(additional);
+    }
+
+    /// Reserve space for `expected_additional` more elements before pushing them, `expected_additional` is clamped so
+    /// that no space is reserved for more than [`Self::MAX`] elements.
+    pub fn reserve_hint(&mut self, expected_additional: usize) {
+        let additional = expected_additional.min(MAX - self.collection.len());
+        self.collection.reserve(additional);
+    }
+
+    /// Reserve space for exactly as many elements as are needed to hold [`Self::MAX`] elements,
+    /// after this no push which keeps the size restriction has to allocate.
+    ///
+    /// Nothing is reserved for an unbounded `MAX` of [`usize::MAX`] like the one of [`NonEmpty`], as that space can
+    /// never be allocated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the inner collection can't allocate enough space.
+    pub fn reserve_up_to_max(&mut self) {
+        if MAX != usize::MAX {
+            self.reserve_exact(MAX - self.collection.len());
+        }
+    }
+
+    /// Shrink the capacity of the inner collection as much as possible, the length stays the same.
+    pub fn shrink_to_fit(&mut self) {
+        self.collection.shrink_to_fit();
+    }
+
+    /// Swap the contents of this collection with `other` in O(1). Both collections have the same bounds, so
+    /// no checks are needed.
+    pub fn swap_with(&mut self, other: &mut Self) {
+        core::mem::swap(&mut self.collection, &mut other.collection);
+    }
+
+    /// Take the inner collection and leave a collection with [`Self::MIN`] default elements behind, this is like
+    /// [`core::mem::take`] but keeps the size restriction.
+    pub fn take(&mut self) -> C
+    where
+        C: Default,
+        T: Default,
+    {
+        core::mem::take(self).into_inner()
+    }
+
+    /// Unwraps the inner collection and lifts the size restriction
+    pub fn into_inner(self) -> C {
+        self.collection
+    }
+
+    /// Convert this collection into a [`SizeRestricted`] with the bounds `NEW_MIN` and `NEW_MAX`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error and the collection if it doesn't fit in the new size restriction (see [`new`](SizeRestricted::new))
+    #[doc(alias = "rebound")]
+    pub fn reshape<const NEW_MIN: usize, const NEW_MAX: usize>(
+        self,
+    ) -> Result<SizeRestricted<T, C, NEW_MIN, NEW_MAX>, (SizeRangeError, C)> {
+        SizeRestricted::new(self.collection)
+    }
+
+    /// Convert this collection into an [`ExactSized`] collection with exactly `N` elements.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error and the collection if its length isn't `N`
+    pub fn try_into_exact<const N: usize>(
+        self,
+    ) -> Result<ExactSized<T, C, N>, (SizeRangeError, C)> {
+        self.reshape::<N, N>()
+    }
+
+    /// Convert this collection into a [`SizeRestricted`] with the wider bounds `NEW_MIN` and `NEW_MAX`, this can't
+    /// fail as every length allowed by the current bounds is allowed by the new bounds.
+    ///
+    /// This fails to compile if `NEW_MIN` is larger than `MIN` or `NEW_MAX` is smaller than `MAX`:
+    ///
+    /// ```compile_fail
+    /// # use srestricted::ExactSized;
+    /// let collection = ExactSized::<i32, Vec<_>, 3>::new(vec![1, 2, 3]).unwrap();
+    /// let _ = collection.into_flexible::<4, 10>();
+    /// ```
+    pub fn into_flexible<const NEW_MIN: usize, const NEW_MAX: usize>(
+        self,
+    ) -> SizeRestricted<T, C, NEW_MIN, NEW_MAX> {
+        const {
+            assert!(
+                NEW_MIN <= MIN && MAX <= NEW_MAX,
+                "The new bounds must contain the current bounds"
+            );
+        };
+        SizeRestricted {
+            collection: self.collection,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Unwraps the inner collection and returns it together with the bounds as `(collection, MIN, MAX)`
+    pub fn into_parts(self) -> (C, usize, usize) {
+        (self.collection, MIN, MAX)
+    }
+
+    /// Map every element with `f` into a new collection of type `D` with the same bounds.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error and the mapped collection if it doesn't fit in the bounds, this can only happen if `D` doesn't
+    /// grow with every push (like a set which deduplicates the mapped elements).
+    pub fn map<B, D, F>(self, f: F) -> Result<SizeRestricted<B, D, MIN, MAX>, (SizeRangeError, D)>
+    where
+        C: IntoIterator<Item = T>,
+        D: LinearSizedCollection<B> + Default,
+        F: FnMut(T) -> B,
+    {
+        let mut mapped = D::default();
+        mapped.reserve(self.collection.len());
+        for val in self.collection.into_iter().map(f) {
+            mapped.push(val);
+        }
+
+        SizeRestricted::new(mapped)
+    }
+
+    /// Split the collection into consecutive chunks of up to `CHUNK` elements, only the last chunk may be shorter.
+    /// An empty collection results in no chunks.
+    ///
+    /// A `CHUNK` size of 0 fails to compile.
+    #[cfg(feature = "alloc")]
+    pub fn chunk_into<const CHUNK: usize>(self) -> Vec<SizeRestricted<T, C, 0, CHUNK>>
+    where
+        C: IntoIterator<Item = T> + Default,
+    {
+        const { assert!(CHUNK >= 1, "The CHUNK size must be at least 1") };
+
+        let mut chunks = Vec::with_capacity(self.collection.len().div_ceil(CHUNK));
+        let mut iter = self.collection.into_iter().peekable();
+        while iter.peek().is_some() {
+            let mut chunk = C::default();
+            chunk.reserve(CHUNK);
+            for val in iter.by_ref().take(CHUNK) {
+                chunk.push(val);
+            }
+
+            chunks.push(SizeRestricted {
+                collection: chunk,
+                _phantom: PhantomData,
+            });
+        }
+
+        chunks
+    }
+
+    /// Split the collection at `len() / 2` into two halves, the second half gets the additional element of an odd
+    /// length. The halves have a MIN of 0 as they can be smaller than [`Self::MIN`].
+    #[cfg(feature = "alloc")]
+    pub fn split_half(self) -> (SizeRestricted<T, C, 0, MAX>, SizeRestricted<T, C, 0, MAX>)
+    where
+        C: Default,
+    {
+        let mut front = self.collection;
+        let back_len = front.len() - front.len() / 2;
+        let mut back_reversed = Vec::with_capacity(back_len);
+        for _ in 0..back_len {
+            back_reversed.extend(front.pop());
+        }
+
+        let mut back = C::default();
+        back.reserve(back_len);
+        while let Some(val) = back_reversed.pop() {
+            back.push(val);
+        }
+
+        (
+            SizeRestricted {
+                collection: front,
+                _phantom: PhantomData,
+            },
+            SizeRestricted {
+                collection: back,
+                _phantom: PhantomData,
+            },
+        )
+    }
+
+    /// Create a [`SizeRestricted`] from a collection and runtime bounds, the reverse of
+    /// [`into_parts`](SizeRestricted::into_parts).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FromPartsError::BoundsMismatch`] and the collection if `min` and `max` are not `MIN` and `MAX`,
+    /// otherwise [`FromPartsError::Size`] if the collection doesn't fit (see [`new`](SizeRestricted::new)).
+    pub fn from_parts(collection: C, min: usize, max: usize) -> Result<Self, (FromPartsError, C)> {
+        if min != MIN || max != MAX {
+            return Err((FromPartsError::BoundsMismatch, collection));
+        }
+
+        Self::new(collection).map_err(|(e, collection)| (FromPartsError::Size(e), collection))
+    }
+
+    /// Get an immutable view into the collection
+    pub fn view(&self) -> &<C as Deref>::Target
+    where
+        C: Deref,
+    {
+        &self.collection
+    }
+
+    /// Get the elements as two contiguous slices without rearranging the collection.
+    ///
+    /// Unlike [`view_mut`](SizeRestricted::view_mut) for a [`VecDeque`](alloc::collections::VecDeque) this does not
+    /// make the collection contiguous.
+    pub fn as_slices(&self) -> (&[T], &[T])
+    where
+        C: AsSlices<T>,
+    {
+        self.collection.as_slices()
+    }
+
+    /// Get a mutable view into the collection.
+    ///
+    /// This is implemented with the [`ViewMut`] trait refer to it for more information on safety
+    pub fn view_mut<'a>(&'a mut self) -> <C as ViewMut<'a>>::MutableView
+    where
+        C: ViewMut<'a>,
+    {
+        self.collection.view_mut()
+    }
+
+    /// Iterate over references to all elements from the front to the back
+    pub fn iter<'a>(&'a self) -> <C as Iterable<'a, T>>::Iter
+    where
+        C: Iterable<'a, T>,
+    {
+        self.collection.iter()
+    }
+
+    /// Iterate over mutable references to all elements from the front to the back.
+    ///
+    /// The iterator can only mutate the elements but never the length of the collection, the collection can't be
+    /// used while the iterator is alive:
+    ///
+    /// ```compile_fail
+    /// # use srestricted::SizeRestricted;
+    /// let mut collection = SizeRestricted::<i32, Vec<_>, 1, 3>::new(vec![1]).unwrap();
+    /// let iter = collection.iter_mut();
+    /// collection.push(2).unwrap();
+    /// drop(iter);
+    /// ```
+    ///
+    /// Iterators of collections like [`LinkedList`](alloc::collections::LinkedList) can't insert elements either:
+    ///
+    /// ```compile_fail
+    /// # use std::collections::LinkedList;
+    /// # use srestricted::SizeRestricted;
+    /// let mut collection = SizeRestricted::<i32, _, 1, 3>::new(LinkedList::from([1])).unwrap();
+    /// collection.iter_mut().insert_next(2);
+    /// ```
+    pub fn iter_mut<'a>(&'a mut self) -> <C as IterableMut<'a, T>>::IterMut
+    where
+        C: IterableMut<'a, T>,
+    {
+        self.collection.iter_mut()
+    }
+
+    /// Get a reference to the first element or [`None`] if the collection is empty
+    pub fn first<'a>(&'a self) -> Option<&'a T>
+    where
+        C: Iterable<'a, T>,
+    {
+        self.iter().next()
+    }
+
+    /// Get a reference to the last element or [`None`] if the collection is empty
+    pub fn last<'a>(&'a self) -> Option<&'a T>
+    where
+        C: Iterable<'a, T>,
+    {
+        self.iter().next_back()
+    }
+
+    /// Get a mutable reference to the first element or [`None`] if the collection is empty
+    pub fn first_mut<'a>(&'a mut self) -> Option<&'a mut T>
+    where
+        C: IterableMut<'a, T>,
+    {
+        self.iter_mut().next()
+    }
+
+    /// Get a mutable reference to the last element or [`None`] if the collection is empty
+    pub fn last_mut<'a>(&'a mut self) -> Option<&'a mut T>
+    where
+        C: IterableMut<'a, T>,
+    {
+        self.iter_mut().next_back()
+    }
+
+    /// Look at the element at the front without removing it, this is the same as [`first`](SizeRestricted::first)
+    pub fn peek_front<'a>(&'a self) -> Option<&'a T>
+    where
+        C: Iterable<'a, T>,
+    {
+        self.first()
+    }
+
+    /// Look at the element at the back without removing it, this is the same as [`last`](SizeRestricted::last)
+    pub fn peek_back<'a>(&'a self) -> Option<&'a T>
+    where
+        C: Iterable<'a, T>,
+    {
+        self.last()
+    }
+
+    /// Mutate the element at the front without removing it, this is the same as
+    /// [`first_mut`](SizeRestricted::first_mut)
+    pub fn peek_front_mut<'a>(&'a mut self) -> Option<&'a mut T>
+    where
+        C: IterableMut<'a, T>,
+    {
+        self.first_mut()
+    }
+
+    /// Mutate the element at the back without removing it, this is the same as
+    /// [`last_mut`](SizeRestricted::last_mut)
+    pub fn peek_back_mut<'a>(&'a mut self) -> Option<&'a mut T>
+    where
+        C: IterableMut<'a, T>,
+    {
+        self.last_mut()
+    }
+
+    /// Get a mutable reference to the first element of a never empty collection.
+    ///
+    /// This fails to compile if `MIN` is 0.
+    pub fn first_mut_nonempty<'a>(&'a mut self) -> &'a mut T
+    where
+        C: IterableMut<'a, T>,
+    {
+        assert!(Self::NON_EMPTY);
+        self.first_mut()
+            .expect("A collection with a MIN of at least 1 is never empty")
+    }
+
+    /// Get a mutable reference to the last element of a never empty collection.
+    ///
+    /// This fails to compile if `MIN` is 0.
+    pub fn last_mut_nonempty<'a>(&'a mut self) -> &'a mut T
+    where
+        C: IterableMut<'a, T>,
+    {
+        assert!(Self::NON_EMPTY);
+        self.last_mut()
+            .expect("A collection with a MIN of at least 1 is never empty")
+    }
+
+    /// Iterate over all elements together with their index from the front to the back, this doesn't need
+    /// random access so it is cheap for collections like [`LinkedList`](alloc::collections::LinkedList).
+    pub fn enumerate<'a>(&'a self) -> core::iter::Enumerate<<C as Iterable<'a, T>>::Iter>
+    where
+        C: Iterable<'a, T>,
+    {
+        self.iter().enumerate()
+    }
+
+    /// Iterate over references to the elements in order and repeat forever.
+    ///
+    /// This fails to compile if `MIN` is 0, so the iterator never ends:
+    ///
+    /// ```compile_fail
+    /// # use srestricted::SizeRestricted;
+    /// let collection = SizeRestricted::<i32, Vec<_>, 0, 3>::new(vec![1]).unwrap();
+    /// let _ = collection.cycle().next();
+    /// ```
+    pub fn cycle<'a>(&'a self) -> core::iter::Cycle<<C as Iterable<'a, T>>::Iter>
+    where
+        C: Iterable<'a, T>,
+        <C as Iterable<'a, T>>::Iter: Clone,
+    {
+        assert!(Self::NON_EMPTY);
+        self.iter().cycle()
+    }
+
+    /// Iterate over references to every `step`-th element starting with the first, see [`Iterator::step_by`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is 0.
+    pub fn iter_step<'a>(&'a self, step: usize) -> core::iter::StepBy<<C as Iterable<'a, T>>::Iter>
+    where
+        C: Iterable<'a, T>,
+    {
+        self.iter().step_by(step)
+    }
+
+    /// Count the elements for which `f` returns `true`
+    pub fn count<'a, F: FnMut(&T) -> bool>(&'a self, mut f: F) -> usize
+    where
+        C: Iterable<'a, T>,
+    {
+        self.iter().filter(|val| f(val)).count()
+    }
+
+    /// Sum up all elements
+    pub fn sum<'a, S: core::iter::Sum<&'a T>>(&'a self) -> S
+    where
+        C: Iterable<'a, T>,
+    {
+        self.iter().sum()
+    }
+
+    /// Reduce all elements from the front to the back into one value, see [`Iterator::fold`].
+    pub fn fold<'a, B, F: FnMut(B, &'a T) -> B>(&'a self, init: B, f: F) -> B
+    where
+        C: Iterable<'a, T>,
+    {
+        self.iter().fold(init, f)
+    }
+
+    /// Reduce the elements from the front to the back into one value until `f` returns [`ControlFlow::Break`],
+    /// see [`Iterator::try_fold`].
+    pub fn try_fold<'a, B, R, F: FnMut(B, &'a T) -> ControlFlow<R, B>>(
+        &'a self,
+        init: B,
+        f: F,
+    ) -> ControlFlow<R, B>
+    where
+        C: Iterable<'a, T>,
+    {
+        self.iter().try_fold(init, f)
+    }
+
+    /// Get the smallest element or [`None`] if the collection is empty, the first one is returned if several
+    /// elements are equally small.
+    ///
+    /// This isn't called `min` as [`Ord::min`] would take precedence in method calls.
+    pub fn min_element<'a>(&'a self) -> Option<&'a T>
+    where
+        C: Iterable<'a, T>,
+        T: Ord,
+    {
+        self.iter().min()
+    }
+
+    /// Get the largest element or [`None`] if the collection is empty, the last one is returned if several
+    /// elements are equally large.
+    ///
+    /// This isn't called `max` as [`Ord::max`] would take precedence in method calls.
+    pub fn max_element<'a>(&'a self) -> Option<&'a T>
+    where
+        C: Iterable<'a, T>,
+        T: Ord,
+    {
+        self.iter().max()
+    }
+
+    /// Get the smallest element of a collection which can't be empty.
+    ///
+    /// This fails to compile if `MIN` is 0.
+    pub fn min_nonempty<'a>(&'a self) -> &'a T
+    where
+        C: Iterable<'a, T>,
+        T: Ord,
+    {
+        assert!(Self::NON_EMPTY);
+        self.min_element()
+            .expect("A collection with a MIN of at least 1 is never empty")
+    }
+
+    /// Get the largest element of a collection which can't be empty.
+    ///
+    /// This fails to compile if `MIN` is 0.
+    pub fn max_nonempty<'a>(&'a self) -> &'a T
+    where
+        C: Iterable<'a, T>,
+        T: Ord,
+    {
+        assert!(Self::NON_EMPTY);
+        self.max_element()
+            .expect("A collection with a MIN of at least 1 is never empty")
+    }
+
+    /// Get the collection as slice, this is the same as [`view`](SizeRestricted::view) for slice backed collections
+    pub fn as_slice(&self) -> &[T]
+    where
+        C: Deref<Target = [T]>,
+    {
+        &self.collection
+    }
+
+    /// Get the collection as mutable slice, this is the same as [`view_mut`](SizeRestricted::view_mut)
+    /// for collections which are viewed as slice
+    pub fn as_mut_slice<'a>(&'a mut self) -> &'a mut [T]
+    where
+        C: ViewMut<'a, MutableView = &'a mut [T]>,
+    {
+        self.collection.view_mut()
+    }
+
+    /// Get a raw pointer to the first element of the collection, see [`slice::as_ptr`].
+    pub fn as_ptr(&self) -> *const T
+    where
+        C: Deref<Target = [T]>,
+    {
+        self.collection.as_ptr()
+    }
+
+    /// Get a mutable raw pointer to the first element of the collection, see [`slice::as_mut_ptr`].
+    ///
+    /// The pointer is only valid for [`len`](SizeRestricted::len) elements, it can't be used to change the length
+    /// of the collection. Writing through the pointer while the collection is used in any other way is undefined
+    /// behavior.
+    pub fn as_mut_ptr<'a>(&'a mut self) -> *mut T
+    where
+        C: ViewMut<'a, MutableView = &'a mut [T]>,
+    {
+        self.collection.view_mut().as_mut_ptr()
+    }
+
+    /// Get a reference to the element at `index` or [`None`] if it is out of bounds
+    pub fn get(&self, index: usize) -> Option<&T>
+    where
+        C: Deref<Target = [T]>,
+    {
+        self.collection.get(index)
+    }
+
+    /// Get a reference to the element at `index` or `default` if it is out of bounds
+    pub fn get_or<'a>(&'a self, index: usize, default: &'a T) -> &'a T
+    where
+        C: Deref<Target = [T]>,
+    {
+        self.collection.get(index).unwrap_or(default)
+    }
+
+    /// Get a reference to the element at `index` or the reference returned by `default` if it is out of bounds,
+    /// `default` is only called if `index` is out of bounds.
+    pub fn get_or_else<'a, F: FnOnce() -> &'a T>(&'a self, index: usize, default: F) -> &'a T
+    where
+        C: Deref<Target = [T]>,
+    {
+        self.collection.get(index).unwrap_or_else(default)
+    }
+
+    /// Get a mutable reference to the element at `index` or [`None`] if it is out of bounds
+    pub fn get_mut<'a>(&'a mut self, index: usize) -> Option<&'a mut T>
+    where
+        C: ViewMut<'a, MutableView = &'a mut [T]>,
+    {
+        self.collection.view_mut().get_mut(index)
+    }
+
+    /// Get a mutable reference to the element at `index`, the collection is extended to `index + 1` elements
+    /// with values returned by `fill` if `index` is out of bounds.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SizeRangeError::TooLarge`] if `index + 1` is larger than [`Self::MAX`], the collection is unchanged.
+    pub fn get_or_extend<'a, F: FnMut() -> T>(
+        &'a mut self,
+        index: usize,
+        fill: F,
+    ) -> Result<&'a mut T, SizeRangeError>
+    where
+        C: ViewMut<'a, MutableView = &'a mut [T]>,
+    {
+        if index >= MAX {
+            return Err(SizeRangeError::TooLarge);
+        }
+
+        if index >= self.collection.len() {
+            self.collection.extend_to_with(index + 1, fill);
+        }
+
+        Ok(&mut self.collection.view_mut()[index])
+    }
+
+    /// Replace the element at `index` with `val` and return the previous element.
+    /// Returns [`None`] if `index` is out of bounds, the length of the collection never changes.
+    pub fn replace<'a>(&'a mut self, index: usize, val: T) -> Option<T>
+    where
+        C: ViewMut<'a, MutableView = &'a mut [T]>,
+    {
+        self.get_mut(index)
+            .map(|elem| core::mem::replace(elem, val))
+    }
+
+    /// Move the element at `index` to the front, the elements before it are shifted back by one. The length doesn't
+    /// change so this never violates the size restriction.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn move_to_front<'a>(&'a mut self, index: usize)
+    where
+        C: ViewMut<'a, MutableView = &'a mut [T]>,
+    {
+        self.collection.view_mut()[..=index].rotate_right(1);
+    }
+
+    /// Iterate over all overlapping windows of length `size`, see [`slice::windows`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    pub fn windows(&self, size: usize) -> core::slice::Windows<'_, T>
+    where
+        C: Deref<Target = [T]>,
+    {
+        self.collection.windows(size)
+    }
+
+    /// Iterate over chunks of length `size`, the last chunk may be shorter. See [`slice::chunks`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    pub fn chunks(&self, size: usize) -> core::slice::Chunks<'_, T>
+    where
+        C: Deref<Target = [T]>,
+    {
+        self.collection.chunks(size)
+    }
+
+    /// Iterate over chunks of exactly length `size`, the remaining elements can be retrieved with
+    /// [`remainder`](core::slice::ChunksExact::remainder). See [`slice::chunks_exact`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    pub fn chunks_exact(&self, size: usize) -> core::slice::ChunksExact<'_, T>
+    where
+        C: Deref<Target = [T]>,
+    {
+        self.collection.chunks_exact(size)
+    }
+
+    /// Split the collection into arrays of length `N` from the front and the remaining `len % N` elements,
+    /// like [`slice::as_chunks`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is 0.
+    pub fn as_chunks<const N: usize>(&self) -> (&[[T; N]], &[T])
+    where
+        C: Deref<Target = [T]>,
+    {
+        assert!(N != 0, "Chunks can't be empty");
+        let slice: &[T] = &self.collection;
+        let (chunks, remainder) = slice.split_at(slice.len() - slice.len() % N);
+        // SAFETY: `chunks` holds exactly `chunks.len() / N` arrays of `N` elements, `[T; N]` has the same layout as
+        // `N` consecutive elements of `T`
+        let chunks = unsafe {
+            core::slice::from_raw_parts(chunks.as_ptr().cast::<[T; N]>(), chunks.len() / N)
+        };
+        (chunks, remainder)
+    }
+
+    /// Iterate over mutable chunks of exactly length `size`, the remaining elements can be retrieved with
+    /// [`into_remainder`](core::slice::ChunksExactMut::into_remainder). See [`slice::chunks_exact_mut`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    pub fn chunks_exact_mut<'a>(&'a mut self, size: usize) -> core::slice::ChunksExactMut<'a, T>
+    where
+        C: ViewMut<'a, MutableView = &'a mut [T]>,
+    {
+        self.collection.view_mut().chunks_exact_mut(size)
+    }
+
+    /// Call `f` on each overlapping mutable window of length `size` from the front to the back. The windows are
+    /// processed one after another, so later windows see the changes made to earlier ones. `f` is never called if
+    /// `size` is larger than the length of the collection.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    pub fn for_each_window_mut<'a, F: FnMut(&mut [T])>(&'a mut self, size: usize, mut f: F)
+    where
+        C: ViewMut<'a, MutableView = &'a mut [T]>,
+    {
+        assert!(size != 0, "window size must be non-zero");
+        let view = self.collection.view_mut();
+        for start in 0..(view.len() + 1).saturating_sub(size) {
+            f(&mut view[start..start + size]);
+        }
+    }
+
+    /// Iterate over chunks of length `size` starting at the end, the last chunk may be shorter. See
+    /// [`slice::rchunks`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    pub fn rchunks(&self, size: usize) -> core::slice::RChunks<'_, T>
+    where
+        C: Deref<Target = [T]>,
+    {
+        self.collection.rchunks(size)
+    }
+
+    /// Iterate over mutable chunks of length `size` starting at the end, the last chunk may be shorter. See
+    /// [`slice::rchunks_mut`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    pub fn rchunks_mut<'a>(&'a mut self, size: usize) -> core::slice::RChunksMut<'a, T>
+    where
+        C: ViewMut<'a, MutableView = &'a mut [T]>,
+    {
+        self.collection.view_mut().rchunks_mut(size)
+    }
+
+    /// Divide the collection into two slices at `mid`, see [`slice::split_at`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > len`.
+    pub fn split_at(&self, mid: usize) -> (&[T], &[T])
+    where
+        C: Deref<Target = [T]>,
+    {
+        self.collection.split_at(mid)
+    }
+
+    /// Get the elements in `range` as slice, see [`slice::get`] for a non panicking variant.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of bounds or its start is greater than its end.
+    pub fn subslice(&self, range: Range<usize>) -> &[T]
+    where
+        C: Deref<Target = [T]>,
+    {
+        &self.collection[range]
+    }
+
+    /// Get the elements in `range` as [`RestrictedSlice`] whichs length is between `NEW_MIN` and `NEW_MAX`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the length of `range` doesn't fit in the new size restriction (see [`RestrictedSlice::new`])
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of bounds or its start is greater than its end.
+    pub fn restricted_subrange<const NEW_MIN: usize, const NEW_MAX: usize>(
+        &self,
+        range: Range<usize>,
+    ) -> Result<RestrictedSlice<'_, T, NEW_MIN, NEW_MAX>, SizeRangeError>
+    where
+        C: Deref<Target = [T]>,
+    {
+        RestrictedSlice::new(self.subslice(range))
+    }
+
+    /// Get the first element and the rest of the collection or [`None`] if it is empty, see [`slice::split_first`].
+    pub fn split_first(&self) -> Option<(&T, &[T])>
+    where
+        C: Deref<Target = [T]>,
+    {
+        self.collection.split_first()
+    }
+
+    /// Get the last element and the rest of the collection or [`None`] if it is empty, see [`slice::split_last`].
+    pub fn split_last(&self) -> Option<(&T, &[T])>
+    where
+        C: Deref<Target = [T]>,
+    {
+        self.collection.split_last()
+    }
+
+    /// Get the first element and the rest of a never empty collection.
+    ///
+    /// This fails to compile if `MIN` is 0.
+    pub fn split_first_nonempty(&self) -> (&T, &[T])
+    where
+        C: Deref<Target = [T]>,
+    {
+        assert!(Self::NON_EMPTY);
+        self.split_first()
+            .expect("A collection with a MIN of at least 1 is never empty")
+    }
+
+    /// Get the last element and the rest of a never empty collection.
+    ///
+    /// This fails to compile if `MIN` is 0.
+    pub fn split_last_nonempty(&self) -> (&T, &[T])
+    where
+        C: Deref<Target = [T]>,
+    {
+        assert!(Self::NON_EMPTY);
+        self.split_last()
+            .expect("A collection with a MIN of at least 1 is never empty")
+    }
+
+    /// Divide the collection into two mutable slices at `mid`, see [`slice::split_at_mut`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > len`.
+    pub fn split_at_mut<'a>(&'a mut self, mid: usize) -> (&'a mut [T], &'a mut [T])
+    where
+        C: ViewMut<'a, MutableView = &'a mut [T]>,
+    {
+        self.collection.view_mut().split_at_mut(mid)
+    }
+
+    /// Sort the collection, see [`slice::sort`]. Sorting doesn't change the length so it can't violate the size restriction.
+    #[cfg(feature = "alloc")]
+    pub fn sort<'a>(&'a mut self)
+    where
+        C: ViewMut<'a, MutableView = &'a mut [T]>,
+        T: Ord,
+    {
+        self.collection.view_mut().sort();
+    }
+
+    /// Sort the collection without preserving the order of equal elements, see [`slice::sort_unstable`].
+    pub fn sort_unstable<'a>(&'a mut self)
+    where
+        C: ViewMut<'a, MutableView = &'a mut [T]>,
+        T: Ord,
+    {
+        self.collection.view_mut().sort_unstable();
+    }
+
+    /// Sort the collection with the comparator function `compare`, see [`slice::sort_by`].
+    #[cfg(feature = "alloc")]
+    pub fn sort_by<'a, F: FnMut(&T, &T) -> Ordering>(&'a mut self, compare: F)
+    where
+        C: ViewMut<'a, MutableView = &'a mut [T]>,
+    {
+        self.collection.view_mut().sort_by(compare);
+    }
+
+    /// Sort the collection with the key extraction function `f`, see [`slice::sort_by_key`].
+    #[cfg(feature = "alloc")]
+    pub fn sort_by_key<'a, K: Ord, F: FnMut(&T) -> K>(&'a mut self, f: F)
+    where
+        C: ViewMut<'a, MutableView = &'a mut [T]>,
+    {
+        self.collection.view_mut().sort_by_key(f);
+    }
+
+    /// Search for `x` in the sorted collection, see [`BinarySearch`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] with the index at which `x` could be inserted to keep the collection sorted if `x` is not found.
+    pub fn binary_search(&self, x: &T) -> Result<usize, usize>
+    where
+        C: BinarySearch<T>,
+        T: Ord,
+    {
+        self.collection.binary_search_by(|elem| elem.cmp(x))
+    }
+
+    /// Search the sorted collection with the comparator function `f`, see [`BinarySearch`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] with the index at which a matching element could be inserted to keep the collection sorted
+    /// if no element matches.
+    pub fn binary_search_by<F: FnMut(&T) -> Ordering>(&self, f: F) -> Result<usize, usize>
+    where
+        C: BinarySearch<T>,
+    {
+        self.collection.binary_search_by(f)
+    }
+
+    /// Search the collection which is sorted by the key extracted with `f` for `key`, see [`BinarySearch`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] with the index at which an element with `key` could be inserted to keep the collection sorted
+    /// if no element matches.
+    pub fn binary_search_by_key<B: Ord, F: FnMut(&T) -> B>(
+        &self,
+        key: &B,
+        mut f: F,
+    ) -> Result<usize, usize>
+    where
+        C: BinarySearch<T>,
+    {
+        self.collection.binary_search_by(|elem| f(elem).cmp(key))
+    }
+
+    /// Get the index of the first element for which `pred` returns `false`. The collection has to be partitioned
+    /// so that all elements for which `pred` returns `true` come first, like a collection sorted by `pred`.
+    ///
+    /// This is the index at which an element could be inserted to keep the partition, see [`slice::partition_point`].
+    pub fn partition_point<F: FnMut(&T) -> bool>(&self, pred: F) -> usize
+    where
+        C: BinarySearch<T>,
+    {
+        self.collection.partition_point(pred)
+    }
+
+    /// Get mutable references to the elements at all `indices` at once.
+    ///
+    /// Returns [`None`] if any index is out of bounds or if an index appears more than once.
+    pub fn get_disjoint_mut<'a, const N: usize>(
+        &'a mut self,
+        indices: [usize; N],
+    ) -> Option<[&'a mut T; N]>
+    where
+        C: ViewMut<'a, MutableView = &'a mut [T]>,
+    {
+        self.collection.view_mut().get_disjoint_mut(indices).ok()
+    }
+}
+
+impl<T, C: LinearSizedCollection<T> + FixedCapacity, const MIN: usize, const MAX: usize>
+    SizeRestricted<T, C, MIN, MAX>
+{
+    /// The static maximum number of elements the inner collection can hold, see [`FixedCapacity`]
+    pub const CAPACITY: usize = C::CAPACITY;
+
+    /// The static maximum length this collection can reach, the smaller value of `MAX` and
+    /// [`CAPACITY`](SizeRestricted::CAPACITY)
+    pub const fn max_capacity() -> usize {
+        if MAX < C::CAPACITY {
+            MAX
+        } else {
+            C::CAPACITY
+        }
+    }
+}
+
+impl<T, C, const MIN1: usize, const MAX1: usize, const MIN2: usize, const MAX2: usize>
+    PartialEq<SizeRestricted<T, C, MIN2, MAX2>> for SizeRestricted<T, C, MIN1, MAX1>
+where
+    C: LinearSizedCollection<T> + PartialEq,
+{
+    fn eq(&self, other: &SizeRestricted<T, C, MIN2, MAX2>) -> bool {
+        self.collection == other.collection
+    }
+}
+
+impl<T, C, const MIN: usize, const MAX: usize> Eq for SizeRestricted<T, C, MIN, MAX> where
+    C: LinearSizedCollection<T> + Eq
+{
+}
+
+/// A [`SizeRestricted`] collection can be compared with its raw inner collection type.
+impl<T, C, const MIN: usize, const MAX: usize> PartialEq<C> for SizeRestricted<T, C, MIN, MAX>
+where
+    C: LinearSizedCollection<T> + PartialEq,
+{
+    fn eq(&self, other: &C) -> bool {
+        self.collection == *other
+    }
+}
+
+impl<T, C, const MIN1: usize, const MAX1: usize, const MIN2: usize, const MAX2: usize>
+    PartialOrd<SizeRestricted<T, C, MIN2, MAX2>> for SizeRestricted<T, C, MIN1, MAX1>
+where
+    C: LinearSizedCollection<T> + PartialOrd,
+{
+    fn partial_cmp(&self, other: &SizeRestricted<T, C, MIN2, MAX2>) -> Option<Ordering> {
+        self.collection.partial_cmp(&other.collection)
+    }
+}
+
+impl<T, C, const MIN: usize, const MAX: usize> Ord for SizeRestricted<T, C, MIN, MAX>
+where
+    C: LinearSizedCollection<T> + Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.collection.cmp(&other.collection)
+    }
+}
+
+impl<T, C, const MIN: usize, const MAX: usize> core::hash::Hash for SizeRestricted<T, C, MIN, MAX>
+where
+    C: LinearSizedCollection<T> + core::hash::Hash,
+{
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.collection.hash(state);
+    }
+}
+
+/// Concatenate all `parts` in order into a new [`SizeRestricted`] collection.
+///
+/// # Errors
+///
+/// Returns [`SizeRangeError::TooLarge`] if the combined length exceeds `MAX`, this is checked before anything is
+/// concatenated. Returns [`SizeRangeError::TooSmall`] if the combined length is less than `MIN`, which is the case
+/// for zero parts if `MIN` is not 0.
+#[cfg(feature = "alloc")]
+pub fn concat<T, C, const MIN: usize, const MAX: usize>(
+    parts: impl IntoIterator<Item = SizeRestricted<T, C, MIN, MAX>>,
+) -> Result<SizeRestricted<T, C, MIN, MAX>, SizeRangeError>
+where
+    C: LinearSizedCollection<T> + IntoIterator<Item = T> + Default,
+{
+    let parts = parts.into_iter().collect::<Vec<_>>();
+    let len = parts
+        .iter()
+        .try_fold(0_usize, |len, part| len.checked_add(part.len()))
+        .filter(|len| *len <= MAX)
+        .ok_or(SizeRangeError::TooLarge)?;
+
+    let mut collection = C::default();
+    collection.reserve(len);
+    for val in parts.into_iter().flatten() {
+        collection.push(val);
+    }
+
+    SizeRestricted::new(collection).map_err(|(e, _)| e)
+}
+
+/// The bounds are printed before the inner collection, e.g. `SizeRestricted<1..=10> [1, 2, 3]`.
+impl<T, C, const MIN: usize, const MAX: usize> core::fmt::Debug for SizeRestricted<T, C, MIN, MAX>
+where
+    C: LinearSizedCollection<T> + core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "SizeRestricted<{MIN}..={MAX}> ")?;
+        self.collection.fmt(f)
+    }
+}
+
+/// [`clone_from`](Clone::clone_from) uses the `clone_from` of the inner collection to reuse its allocation.
+impl<T, C, const MIN: usize, const MAX: usize> Clone for SizeRestricted<T, C, MIN, MAX>
+where
+    C: LinearSizedCollection<T> + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            collection: self.collection.clone(),
+            _phantom: PhantomData,
+        }
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        self.collection.clone_from(&source.collection);
+    }
+}
+
+/// Dereferences to the target of the inner collection, like [`view`](SizeRestricted::view)
+impl<T, C, const MIN: usize, const MAX: usize> Deref for SizeRestricted<T, C, MIN, MAX>
+where
+    C: LinearSizedCollection<T> + Deref,
+{
+    type Target = C::Target;
+
+    fn deref(&self) -> &Self::Target {
+        &self.collection
+    }
+}
+
+/// Only collections which dereference to a slice can be dereferenced mutably as a slice can't change its length
+impl<T, C, const MIN: usize, const MAX: usize> DerefMut for SizeRestricted<T, C, MIN, MAX>
+where
+    C: LinearSizedCollection<T> + DerefMut<Target = [T]>,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.collection
+    }
+}
+
+/// Creates a `SizeRestricted` collection with a size of `MIN`
+impl<T, C, const MIN: usize, const MAX: usize> Default for SizeRestricted<T, C, MIN, MAX>
+where
+    C: LinearSizedCollection<T> + Default,
+    T: Default,
+{
+    fn default() -> Self {
+        Self::with_min_fill(Default::default)
+    }
+}
+
+impl<T, C, const MIN: usize, const MAX: usize> IntoIterator for SizeRestricted<T, C, MIN, MAX>
+where
+    C: LinearSizedCollection<T> + IntoIterator<Item = T>,
+{
+    type IntoIter = C::IntoIter;
+    type Item = T;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_inner().into_iter()
+    }
+}
+
+impl<'a, T: 'a, C, const MIN: usize, const MAX: usize> IntoIterator
+    for &'a SizeRestricted<T, C, MIN, MAX>
+where
+    C: LinearSizedCollection<T> + Iterable<'a, T>,
+{
+    type IntoIter = C::Iter;
+    type Item = &'a T;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T: 'a, C, const MIN: usize, const MAX: usize> IntoIterator
+    for &'a mut SizeRestricted<T, C, MIN, MAX>
+where
+    C: LinearSizedCollection<T> + IterableMut<'a, T>,
+{
+    type IntoIter = C::IterMut;
+    type Item = &'a mut T;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// Elements which don't fit are dropped, the iterator is not consumed any further once the collection holds `MAX`
+/// elements. Use [`SizeRestricted::extend_saturating`] to count the elements which don't fit.
+impl<T, C, const MIN: usize, const MAX: usize> Extend<T> for SizeRestricted<T, C, MIN, MAX>
+where
+    C: LinearSizedCollection<T>,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.fill_from(&mut iter.into_iter());
+    }
+}
+
+/// Copies the elements, elements which don't fit are ignored like in the [`Extend<T>`] implementation.
+impl<'a, T: Copy + 'a, C, const MIN: usize, const MAX: usize> Extend<&'a T>
+    for SizeRestricted<T, C, MIN, MAX>
+where
+    C: LinearSizedCollection<T>,
+{
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.fill_from(&mut iter.into_iter().copied());
+    }
+}
+
+#[cfg(feature = "impl_serde")]
+impl<T, C: LinearSizedCollection<T> + serde::Serialize, const MIN: usize, const MAX: usize>
+    serde::Serialize for SizeRestricted<T, C, MIN, MAX>
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.collection.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "impl_serde")]
+impl<
+        'de,
+        T,
+        C: LinearSizedCollection<T> + serde::Deserialize<'de>,
+        const MIN: usize,
+        const MAX: usize,
+    > serde::Deserialize<'de> for SizeRestricted<T, C, MIN, MAX>
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let collection = C::deserialize(deserializer)?;
+        Self::new(collection).map_err(|(e, _)| serde::de::Error::custom(e))
+    }
+}
+
+/// The number of elements an arbitrary [`SizeRestricted`] collection has at most in addition to `MIN`, this keeps
+/// collections with a large `MAX` like [`NonEmpty`] reasonably small.
+#[cfg(feature = "impl_arbitrary")]
+const ARBITRARY_EXTRA_LEN: usize = 256;
+
+/// The length of a generated collection is in between `MIN` and `MAX`, but at most 256 larger than `MIN`.
+///
+/// Elements are only pushed while [`try_reserve`](LinearSizedCollection::try_reserve) succeeds, so collections with a
+/// fixed capacity are not filled beyond it. A collection which doesn't reach `MIN` (because of its capacity or because
+/// a set deduplicated the generated elements) results in [`arbitrary::Error::IncorrectFormat`].
+#[cfg(feature = "impl_arbitrary")]
+impl<'a, T, C, const MIN: usize, const MAX: usize> arbitrary::Arbitrary<'a>
+    for SizeRestricted<T, C, MIN, MAX>
+where
+    T: arbitrary::Arbitrary<'a>,
+    C: LinearSizedCollection<T> + Default,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let max = MAX.min(MIN.saturating_add(ARBITRARY_EXTRA_LEN));
+        let len = u.int_in_range(MIN..=max)?;
+        let mut collection = C::default();
+        for _ in collection.len()..len {
+            if collection.try_reserve(1).is_err() {
+                break;
+            }
+            collection.push(T::arbitrary(u)?);
+        }
+
+        Self::new(collection).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod size_restricted_test {
+    use alloc::{vec, vec::Vec};
+
+    use crate::{
+        ExactSized, FromPartsError, LinearSizedCollection, MutationReport, NonEmpty, ReserveError,
+        SelectError, SizeRangeError, SizeRestricted, TryPushError,
+    };
+
+    /// A collection which only implements the required methods of [`LinearSizedCollection`] to test the default implementations
+    #[derive(Debug, Default, Clone)]
+    pub struct Stack<T>(Vec<T>);
+
+    impl<T> LinearSizedCollection<T> for Stack<T> {
+        fn len(&self) -> usize {
+            self.0.len()
+        }
+
+        fn push(&mut self, val: T) {
+            self.0.push(val);
+        }
+
+        fn pop(&mut self) -> Option<T> {
+            self.0.pop()
+        }
+
+        fn reserve(&mut self, additional: usize) {
+            self.0.reserve(additional);
+        }
+    }
+
+    crate::test::complete_test!(crate::size_restricted_test::Stack::default(), stack_test);
+
+    /// A collection which always fails to allocate
+    #[derive(Debug, Default)]
+    pub struct AllocFailing<T>(Vec<T>);
+
+    impl<T> LinearSizedCollection<T> for AllocFailing<T> {
+        fn len(&self) -> usize {
+            self.0.len()
+        }
+
+        fn push(&mut self, val: T) {
+            self.0.push(val);
+        }
+
+        fn pop(&mut self) -> Option<T> {
+            self.0.pop()
+        }
+
+        fn reserve(&mut self, _additional: usize) {
+            panic!("AllocFailing can't allocate");
+        }
+
+        fn try_reserve(&mut self, _additional: usize) -> Result<(), ReserveError> {
+            Err(ReserveError)
+        }
+    }
+
+    #[test]
+    fn try_push() {
+        let mut collection = SizeRestricted::<i32, _, 0, 2>::new(vec![1]).unwrap();
+        collection.try_push(2).unwrap();
+        assert_eq!(collection.try_push(3), Err(TryPushError::SizeExceeded(3)));
+        assert_eq!(collection.inner(), &vec![1, 2]);
+
+        let mut failing =
+            SizeRestricted::<i32, AllocFailing<_>, 0, 2>::new(AllocFailing::default()).unwrap();
+        let e = failing.try_push(1).unwrap_err();
+        assert_eq!(e, TryPushError::AllocFailed(1));
+        assert_eq!(e.into_inner(), 1);
+        assert!(failing.is_empty());
+    }
+
+    #[test]
+    fn extend_from_slice() {
+        let mut collection = SizeRestricted::<i32, _, 1, 5>::new(vec![1, 2]).unwrap();
+        collection.extend_from_slice(&[3, 4, 5]).unwrap();
+        assert_eq!(collection.inner(), &vec![1, 2, 3, 4, 5]);
+
+        let mut collection = SizeRestricted::<i32, _, 1, 5>::new(vec![1, 2]).unwrap();
+        assert_eq!(
+            collection.extend_from_slice(&[3, 4, 5, 6]),
+            Err(SizeRangeError::TooLarge)
+        );
+        assert_eq!(collection.inner(), &vec![1, 2]);
+
+        let mut stack = SizeRestricted::<i32, Stack<_>, 0, 5>::default();
+        stack.extend_from_slice(&[1, 2, 3]).unwrap();
+        assert_eq!(stack.inner().0, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn try_push_all() {
+        let mut collection = SizeRestricted::<i32, _, 1, 5>::new(vec![1, 2]).unwrap();
+        collection.try_push_all([3, 4, 5]).unwrap();
+        assert_eq!(collection.inner(), &vec![1, 2, 3, 4, 5]);
+
+        let mut collection = SizeRestricted::<i32, _, 1, 5>::new(vec![1, 2]).unwrap();
+        assert_eq!(
+            collection.try_push_all(3..7),
+            Err((SizeRangeError::TooLarge, vec![3, 4, 5, 6]))
+        );
+        assert_eq!(collection.inner(), &vec![1, 2]);
+
+        collection.try_push_all([]).unwrap();
+        assert_eq!(collection.inner(), &vec![1, 2]);
+    }
+
+    #[test]
+    fn parts() {
+        let collection = SizeRestricted::<i32, _, 1, 5>::new(vec![1, 2]).unwrap();
+        let (inner, min, max) = collection.into_parts();
+        assert_eq!((min, max), (1, 5));
+
+        let collection = SizeRestricted::<i32, _, 1, 5>::from_parts(inner, min, max).unwrap();
+        assert_eq!(collection.inner(), &vec![1, 2]);
+
+        let inner = collection.into_inner();
+        assert_eq!(
+            SizeRestricted::<i32, _, 1, 5>::from_parts(inner.clone(), 1, 6),
+            Err((FromPartsError::BoundsMismatch, vec![1, 2]))
+        );
+        assert_eq!(
+            SizeRestricted::<i32, _, 1, 5>::from_parts(inner, 0, 5),
+            Err((FromPartsError::BoundsMismatch, vec![1, 2]))
+        );
+        assert_eq!(
+            SizeRestricted::<i32, _, 1, 5>::from_parts(vec![], 1, 5),
+            Err((FromPartsError::Size(SizeRangeError::TooSmall), vec![]))
+        );
+    }
+
+    #[test]
+    fn mutate_reports_resize() {
+        let mut collection = SizeRestricted::<i32, _, 1, 3>::new(vec![1, 2]).unwrap();
+        assert!(!collection.mutate(|| 0, |inner| inner.push(3)));
+        assert_eq!(collection.inner(), &vec![1, 2, 3]);
+
+        assert!(collection.mutate(|| 0, |inner| inner.clear()));
+        assert_eq!(collection.inner(), &vec![0, 0, 0]);
+
+        collection.mutate_silent(|| 0, |inner| inner.push(4));
+        assert_eq!(collection.inner(), &vec![0]);
+    }
+
+    #[test]
+    fn clone_from_reuses_allocation() {
+        let mut collection = SizeRestricted::<i32, _, 1, 100>::new(vec![0; 100]).unwrap();
+        let capacity = collection.inner().capacity();
+        let source = SizeRestricted::<i32, _, 1, 100>::new(vec![1, 2]).unwrap();
+
+        collection.clone_from(&source);
+        assert_eq!(collection, source);
+        assert_eq!(collection.len(), 2);
+        assert!(collection.inner().capacity() >= capacity);
+    }
+
+    #[test]
+    fn map() {
+        use alloc::{
+            collections::{BTreeSet, VecDeque},
+            string::{String, ToString},
+        };
+
+        let collection = NonEmpty::<i32, Vec<_>>::new(vec![1, 2, 3]).unwrap();
+        let mapped: NonEmpty<String, Vec<_>> = collection.map(|val| val.to_string()).unwrap();
+        assert_eq!(mapped.inner(), &vec!["1", "2", "3"]);
+
+        let collection = SizeRestricted::<i32, _, 2, 4>::new(vec![1, 2]).unwrap();
+        let mapped = collection.map::<_, VecDeque<_>, _>(|val| val * 2).unwrap();
+        assert_eq!(mapped.into_parts(), (VecDeque::from([2, 4]), 2, 4));
+
+        let collection = SizeRestricted::<i32, _, 2, 4>::new(vec![1, 2, 3]).unwrap();
+        assert_eq!(
+            collection.map::<_, BTreeSet<_>, _>(|val| val / 4),
+            Err((SizeRangeError::TooSmall, BTreeSet::from([0])))
+        );
+    }
+
+    #[test]
+    fn grow_to() {
+        let mut collection = SizeRestricted::<i32, _, 1, 4>::new(vec![1]).unwrap();
+        collection.grow_to(4, 0).unwrap();
+        assert_eq!(collection.inner(), &vec![1, 0, 0, 0]);
+
+        assert_eq!(collection.grow_to(5, 0), Err(SizeRangeError::TooLarge));
+        collection.grow_to(2, 0).unwrap();
+        assert_eq!(collection.len(), 4);
+    }
+
+    #[test]
+    fn shrink_to_checked() {
+        let mut collection = SizeRestricted::<i32, _, 2, 4>::new(vec![1, 2, 3, 4]).unwrap();
+        collection.shrink_to_checked(2).unwrap();
+        assert_eq!(collection.inner(), &vec![1, 2]);
+
+        assert_eq!(
+            collection.shrink_to_checked(1),
+            Err(SizeRangeError::TooSmall)
+        );
+        collection.shrink_to_checked(3).unwrap();
+        assert_eq!(collection.inner(), &vec![1, 2]);
+    }
+
+    const ACCEPTS: bool = SizeRestricted::<i32, Vec<i32>, 2, 8>::accepts_len(5);
+    const _: () = assert!(ACCEPTS);
+
+    #[test]
+    fn accepts_len() {
+        type Restricted = SizeRestricted<i32, Vec<i32>, 2, 8>;
+        assert!(!Restricted::accepts_len(1));
+        assert!(Restricted::accepts_len(2));
+        assert!(Restricted::accepts_len(8));
+        assert!(!Restricted::accepts_len(9));
+        assert!(ExactSized::<i32, Vec<i32>, 0>::accepts_len(0));
+    }
+
+    #[test]
+    fn extract_if() {
+        let mut collection = SizeRestricted::<i32, _, 2, 10>::new(vec![1, 2, 3, 4, 5]).unwrap();
+        assert_eq!(collection.extract_if(|val| *val % 2 == 0), Ok(vec![2, 4]));
+        assert_eq!(collection.inner(), &vec![1, 3, 5]);
+
+        assert_eq!(
+            collection.extract_if(|val| *val > 1),
+            Err(SizeRangeError::TooSmall)
+        );
+        assert_eq!(collection.inner(), &vec![1, 3, 5]);
+
+        let mut stack = SizeRestricted::<i32, Stack<_>, 0, 10>::default();
+        stack.extend_from_slice(&[1, 2, 3, 4]).unwrap();
+        assert_eq!(stack.extract_if(|val| *val < 3), Ok(vec![1, 2]));
+        assert_eq!(stack.inner().0, vec![3, 4]);
+
+        let mut collection = SizeRestricted::<i32, _, 2, 10>::new(vec![1, 2, 3, 4]).unwrap();
+        let mut calls = 0;
+        let result = collection.extract_if(|val| {
+            calls += 1;
+            *val *= 10;
+            calls > 1
+        });
+        assert_eq!(result, Err(SizeRangeError::TooSmall));
+        assert_eq!(calls, 4);
+        assert_eq!(collection.inner(), &vec![1, 2, 3, 4]);
+
+        let mut calls = 0;
+        let result = collection.extract_if(|val| {
+            calls += 1;
+            *val *= 10;
+            calls > 2
+        });
+        assert_eq!(result, Ok(vec![30, 40]));
+        assert_eq!(calls, 4);
+        assert_eq!(collection.inner(), &vec![10, 20]);
+    }
+
+    #[test]
+    fn concat() {
+        type Part = SizeRestricted<i32, Vec<i32>, 1, 5>;
+
+        let parts = [vec![1, 2], vec![3], vec![4, 5]].map(|part| Part::new(part).unwrap());
+        assert_eq!(crate::concat(parts).unwrap().inner(), &vec![1, 2, 3, 4, 5]);
+
+        let parts = [vec![1, 2, 3], vec![4, 5, 6]].map(|part| Part::new(part).unwrap());
+        assert_eq!(crate::concat(parts), Err(SizeRangeError::TooLarge));
+
+        assert_eq!(
+            crate::concat::<i32, Vec<_>, 1, 5>([]),
+            Err(SizeRangeError::TooSmall)
+        );
+        assert!(crate::concat::<i32, Vec<_>, 0, 5>([]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn reserve_up_to_max() {
+        let mut collection = SizeRestricted::<i32, _, 1, 64>::new(vec![1, 2]).unwrap();
+        collection.reserve_up_to_max();
+        assert!(collection.inner().capacity() >= 64);
+
+        let mut collection = NonEmpty::<i32, Vec<_>>::new(vec![1, 2]).unwrap();
+        let capacity = collection.inner().capacity();
+        collection.reserve_up_to_max();
+        assert_eq!(collection.inner().capacity(), capacity);
+
+        let mut collection = SizeRestricted::<i32, _, 1, 8>::new(vec![1]).unwrap();
+        collection.reserve_exact(100);
+        assert!(collection.inner().capacity() >= 8);
+        assert!(collection.inner().capacity() < 100);
+    }
+
+    #[test]
+    fn fits_range() {
+        let collection = SizeRestricted::<i32, _, 1, 10>::new(vec![1, 2, 3]).unwrap();
+        assert_eq!(collection.fits_range(3..=3), Ok(()));
+        assert_eq!(collection.fits_range(0..=5), Ok(()));
+        assert_eq!(collection.fits_range(4..=5), Err(SizeRangeError::TooSmall));
+        assert_eq!(collection.fits_range(1..=2), Err(SizeRangeError::TooLarge));
+    }
+
+    #[test]
+    fn from_fn() {
+        let collection = SizeRestricted::<usize, Vec<_>, 1, 5>::from_fn(4, |i| i * 2).unwrap();
+        assert_eq!(collection.inner(), &vec![0, 2, 4, 6]);
+
+        let mut calls = 0;
+        let result = SizeRestricted::<usize, Vec<_>, 1, 5>::from_fn(6, |i| {
+            calls += 1;
+            i
+        });
+        assert_eq!(result, Err(SizeRangeError::TooLarge));
+        assert_eq!(
+            SizeRestricted::<usize, Vec<_>, 1, 5>::from_fn(0, |i| i),
+            Err(SizeRangeError::TooSmall)
+        );
+        assert_eq!(calls, 0);
+    }
+
+    #[test]
+    fn mutate_reporting() {
+        let mut collection = SizeRestricted::<i32, _, 2, 4>::new(vec![1, 2]).unwrap();
+        let report = collection.mutate_reporting(|| 0, |inner| inner.push(3));
+        assert_eq!(
+            report,
+            MutationReport {
+                pre_len: 2,
+                post_mutator_len: 3,
+                final_len: 3,
+                correction: None,
+            }
+        );
+
+        let report = collection.mutate_reporting(|| 0, |inner| inner.extend([4, 5, 6]));
+        assert_eq!(report.post_mutator_len, 6);
+        assert_eq!(report.final_len, 2);
+        assert_eq!(report.correction, Some(SizeRangeError::TooLarge));
+
+        let report = collection.mutate_reporting(|| 0, |inner| inner.clear());
+        assert_eq!(report.pre_len, 2);
+        assert_eq!(report.post_mutator_len, 0);
+        assert_eq!(report.final_len, 4);
+        assert_eq!(report.correction, Some(SizeRangeError::TooSmall));
+        assert_eq!(collection.inner(), &vec![0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn reshape() {
+        let collection = SizeRestricted::<i32, _, 2, 4>::new(vec![1, 2, 3]).unwrap();
+        let widened = collection.reshape::<0, 10>().unwrap();
+        assert_eq!(widened.inner(), &vec![1, 2, 3]);
+        let narrowed = widened.clone().reshape::<3, 3>().unwrap();
+        assert_eq!(narrowed.inner(), &vec![1, 2, 3]);
+
+        assert_eq!(
+            widened.clone().reshape::<4, 10>(),
+            Err((SizeRangeError::TooSmall, vec![1, 2, 3]))
+        );
+        assert_eq!(
+            widened.reshape::<0, 2>(),
+            Err((SizeRangeError::TooLarge, vec![1, 2, 3]))
+        );
+    }
+
+    #[test]
+    fn make_fit_targets() {
+        let mut collection = vec![1];
+        SizeRestricted::<i32, _, 2, 5>::make_fit_with(&mut collection, || 0);
+        assert_eq!(collection, vec![1, 0, 0, 0, 0]);
+
+        let mut collection = vec![1];
+        SizeRestricted::<i32, _, 2, 5>::make_fit_to_min_with(&mut collection, || 0);
+        assert_eq!(collection, vec![1, 0]);
+
+        let mut collection = vec![1, 2, 3, 4, 5, 6];
+        SizeRestricted::<i32, _, 2, 5>::make_fit_with(&mut collection, || 0);
+        assert_eq!(collection, vec![1, 2]);
+
+        let mut collection = vec![1, 2, 3, 4, 5, 6];
+        SizeRestricted::<i32, _, 2, 5>::make_fit_to_min_with(&mut collection, || 0);
+        assert_eq!(collection, vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn new_truncating() {
+        let collection =
+            SizeRestricted::<i32, _, 1, 3>::new_truncating(vec![1, 2, 3, 4, 5]).unwrap();
+        assert_eq!(collection.inner(), &vec![1, 2, 3]);
+
+        let collection = SizeRestricted::<i32, _, 1, 3>::new_truncating(vec![1, 2]).unwrap();
+        assert_eq!(collection.inner(), &vec![1, 2]);
+
+        let (e, collection) = SizeRestricted::<i32, _, 3, 5>::new_truncating(vec![1]).unwrap_err();
+        assert_eq!(e, SizeRangeError::TooSmall);
+        assert_eq!(collection, vec![1]);
+    }
+
+    #[test]
+    fn compare_different_bounds() {
+        let a = SizeRestricted::<i32, _, 1, 10>::new(vec![1, 2, 3]).unwrap();
+        let b = SizeRestricted::<i32, _, 2, 5>::new(vec![1, 2, 3]).unwrap();
+        let c = SizeRestricted::<i32, _, 0, 3>::new(vec![1, 2, 4]).unwrap();
+
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert!(b < c);
+        assert_eq!(a.partial_cmp(&b), Some(core::cmp::Ordering::Equal));
+    }
+
+    #[test]
+    fn retain() {
+        let mut collection = SizeRestricted::<i32, _, 2, 10>::new(vec![1, 2, 3, 4, 5]).unwrap();
+        collection.retain(|val| val % 2 == 1).unwrap();
+        assert_eq!(collection.inner(), &vec![1, 3, 5]);
+
+        assert_eq!(
+            collection.retain(|val| *val > 3),
+            Err(SizeRangeError::TooSmall)
+        );
+        assert_eq!(collection.inner(), &vec![1, 3, 5]);
+    }
+
+    #[test]
+    fn retain_stateful() {
+        let mut collection = SizeRestricted::<i32, _, 2, 10>::new(vec![1, 2, 3, 4]).unwrap();
+        let mut calls = 0;
+        assert_eq!(
+            collection.retain(|_| {
+                calls += 1;
+                calls <= 1
+            }),
+            Err(SizeRangeError::TooSmall)
+        );
+        assert_eq!(calls, 4);
+        assert_eq!(collection.inner(), &vec![1, 2, 3, 4]);
+
+        let mut calls = 0;
+        collection
+            .retain(|_| {
+                calls += 1;
+                calls <= 2
+            })
+            .unwrap();
+        assert_eq!(calls, 4);
+        assert_eq!(collection.inner(), &vec![1, 2]);
+    }
+
+    #[test]
+    fn retain_mut() {
+        let mut collection = SizeRestricted::<i32, _, 2, 10>::new(vec![1, 2, 3, 4, 5]).unwrap();
+        let mut calls = 0;
+        collection
+            .retain_mut(|val| {
+                calls += 1;
+                *val *= 10;
+                *val != 30
+            })
+            .unwrap();
+        assert_eq!(collection.inner(), &vec![10, 20, 40, 50]);
+        assert_eq!(calls, 5);
+
+        let result = collection.retain_mut(|val| {
+            *val += 1;
+            *val > 45
+        });
+        assert_eq!(result, Err(SizeRangeError::TooSmall));
+        assert_eq!(collection.inner(), &vec![10, 20, 40, 50]);
+
+        let mut collection =
+            SizeRestricted::<i32, _, 1, 5>::new(AllocFailing(vec![1, 2, 3])).unwrap();
+        collection
+            .retain_mut(|val| {
+                *val += 1;
+                *val % 2 == 0
+            })
+            .unwrap();
+        assert_eq!(collection.inner().0, vec![2, 4]);
+    }
+
+    #[test]
+    fn retain_mut_stateful() {
+        let mut collection = SizeRestricted::<i32, _, 2, 10>::new(vec![1, 2, 3, 4]).unwrap();
+        let mut calls = 0;
+        let result = collection.retain_mut(|val| {
+            calls += 1;
+            *val = 0;
+            calls <= 1
+        });
+        assert_eq!(result, Err(SizeRangeError::TooSmall));
+        assert_eq!(calls, 4);
+        assert_eq!(collection.inner(), &vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn invalid_state_guards() {
+        let mut too_small = SizeRestricted::<i32, Vec<_>, 3, 5> {
+            collection: vec![1, 2],
+            _phantom: core::marker::PhantomData,
+        };
+        assert_eq!(too_small.pop(), None);
+        assert_eq!(too_small.swap_remove(0), None);
+        assert_eq!(too_small.len(), 2);
+
+        let mut too_large = SizeRestricted::<i32, Vec<_>, 0, 1> {
+            collection: vec![1, 2],
+            _phantom: core::marker::PhantomData,
+        };
+        assert_eq!(too_large.push(3), Err((SizeRangeError::TooLarge, 3)));
+        assert_eq!(too_large.len(), 2);
+    }
+
+    #[test]
+    fn swap_remove_moves_last() {
+        let mut collection = SizeRestricted::<i32, _, 2, 10>::new(vec![1, 2, 3, 4]).unwrap();
+        assert_eq!(collection.swap_remove(0), Some(1));
+        assert_eq!(collection.inner(), &vec![4, 2, 3]);
+    }
+
+    #[test]
+    fn swap_remove_min_guard() {
+        let mut collection = SizeRestricted::<i32, _, 3, 10>::new(vec![1, 2, 3]).unwrap();
+        assert_eq!(collection.swap_remove(0), None);
+        assert_eq!(collection.inner(), &vec![1, 2, 3]);
+
+        let mut collection = SizeRestricted::<i32, Vec<_>, 0, 10>::new(vec![1, 2, 3]).unwrap();
+        assert_eq!(collection.swap_remove(3), None);
+        assert_eq!(collection.len(), 3);
+    }
+
+    #[test]
+    fn last_mut_nonempty() {
+        let mut collection = NonEmpty::<i32, Vec<_>>::new(vec![1, 2, 3]).unwrap();
+        *collection.last_mut_nonempty() += 10;
+        *collection.first_mut_nonempty() -= 1;
+        assert_eq!(collection.inner(), &vec![0, 2, 13]);
+
+        *collection.last_mut().unwrap() = 4;
+        assert_eq!(collection.first(), Some(&0));
+        assert_eq!(collection.last(), Some(&4));
+
+        let mut empty = SizeRestricted::<i32, Vec<_>, 0, 1>::default();
+        assert_eq!(empty.first_mut(), None);
+        assert_eq!(empty.last(), None);
+    }
+
+    #[test]
+    fn get_disjoint_mut() {
+        let mut collection = SizeRestricted::<i32, _, 1, 10>::new(vec![1, 2, 3, 4]).unwrap();
+        let [a, b] = collection.get_disjoint_mut([3, 0]).unwrap();
+        core::mem::swap(a, b);
+        assert_eq!(collection.inner(), &vec![4, 2, 3, 1]);
+
+        assert!(collection.get_disjoint_mut([1, 1]).is_none());
+        assert!(collection.get_disjoint_mut([0, 4]).is_none());
+    }
+
+    #[test]
+    fn shrink_to_fit_keeps_len() {
+        let mut collection = SizeRestricted::<i32, Vec<_>, 2, 1000>::new(vec![1, 2]).unwrap();
+        for i in 0..998 {
+            collection.push(i).unwrap();
+        }
+        while collection.pop().is_some() {}
+
+        let capacity = collection.inner().capacity();
+        collection.shrink_to_fit();
+        assert_eq!(collection.len(), 2);
+        assert!(collection.inner().capacity() < capacity);
+    }
+
+    #[test]
+    fn as_slice() {
+        let mut collection = SizeRestricted::<i32, _, 1, 10>::new(vec![1, 2, 3]).unwrap();
+        assert_eq!(collection.as_slice(), &[1, 2, 3]);
+
+        collection.as_mut_slice()[0] = 10;
+        assert_eq!(collection.as_slice(), &[10, 2, 3]);
+    }
+
+    #[test]
+    fn deref_slice() {
+        let mut collection = SizeRestricted::<i32, _, 1, 10>::new(vec![1, 2, 3]).unwrap();
+        assert_eq!(collection.iter().sum::<i32>(), 6);
+        assert!(collection.contains(&2));
+
+        collection.iter_mut().for_each(|val| *val *= 2);
+        collection.reverse();
+        assert_eq!(&*collection, &[6, 4, 2]);
+    }
+
+    #[test]
+    fn replace() {
+        let mut collection = SizeRestricted::<i32, _, 1, 10>::new(vec![1, 2, 3]).unwrap();
+        assert_eq!(collection.replace(1, 20), Some(2));
+        assert_eq!(collection.get(1), Some(&20));
+        assert_eq!(collection.len(), 3);
+
+        assert_eq!(collection.replace(3, 40), None);
+        assert_eq!(collection.inner(), &vec![1, 20, 3]);
+    }
+
+    #[test]
+    fn iter_rev() {
+        let mut collection = NonEmpty::<i32, Vec<_>>::new(vec![1, 2, 3]).unwrap();
+        assert_eq!(
+            collection.iter().rev().collect::<Vec<_>>(),
+            vec![&3, &2, &1]
+        );
+
+        for (i, val) in collection.iter_mut().rev().enumerate() {
+            *val = i as i32;
+        }
+        assert_eq!(collection.inner(), &vec![2, 1, 0]);
+    }
+
+    #[test]
+    fn binary_search() {
+        let values = vec![1, 3, 3, 5, 8];
+        let collection = SizeRestricted::<i32, _, 1, 10>::new(values.clone()).unwrap();
+        for x in 0..10 {
+            assert_eq!(
+                collection.binary_search(&x).is_ok(),
+                values.binary_search(&x).is_ok()
+            );
+        }
+        assert_eq!(collection.binary_search(&5), Ok(3));
+        assert_eq!(collection.binary_search(&4), Err(3));
+        assert_eq!(collection.binary_search(&9), Err(5));
+        assert_eq!(collection.binary_search_by_key(&10, |x| x * 2), Ok(3));
+    }
+
+    #[test]
+    fn split_first_last() {
+        let collection = NonEmpty::<i32, Vec<_>>::new(vec![1, 2, 3]).unwrap();
+        assert_eq!(collection.split_first(), Some((&1, &[2, 3][..])));
+        assert_eq!(collection.split_last(), Some((&3, &[1, 2][..])));
+        assert_eq!(collection.split_first_nonempty(), (&1, &[2, 3][..]));
+        assert_eq!(collection.split_last_nonempty(), (&3, &[1, 2][..]));
+
+        let single = NonEmpty::<i32, Vec<_>>::new(vec![1]).unwrap();
+        assert_eq!(single.split_first_nonempty(), (&1, &[][..]));
+
+        let empty = SizeRestricted::<i32, Vec<_>, 0, 3>::default();
+        assert_eq!(empty.split_first(), None);
+        assert_eq!(empty.split_last(), None);
+    }
+
+    #[test]
+    fn sort() {
+        let mut collection = SizeRestricted::<i32, _, 1, 6>::new(vec![5, 1, 4, 2, 3]).unwrap();
+        collection.sort();
+        assert_eq!(collection.inner(), &vec![1, 2, 3, 4, 5]);
+
+        collection.sort_by(|a, b| b.cmp(a));
+        assert_eq!(collection.inner(), &vec![5, 4, 3, 2, 1]);
+
+        collection.sort_by_key(|val| (*val % 2, *val));
+        assert_eq!(collection.inner(), &vec![2, 4, 1, 3, 5]);
+
+        collection.sort_unstable();
+        assert_eq!(collection.inner(), &vec![1, 2, 3, 4, 5]);
+        assert_eq!(collection.len(), 5);
+
+        let mut deque =
+            SizeRestricted::<i32, _, 1, 6>::new(alloc::collections::VecDeque::from([3, 1, 2]))
+                .unwrap();
+        deque.sort();
+        assert_eq!(deque.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn partition_point() {
+        let values = vec![1, 3, 3, 5, 8];
+        let collection = SizeRestricted::<i32, _, 1, 6>::new(values.clone()).unwrap();
+        for x in 0..10 {
+            assert_eq!(
+                collection.partition_point(|val| *val < x),
+                values.partition_point(|val| *val < x)
+            );
+        }
+    }
+
+    #[test]
+    fn partition_point_insert() {
+        let mut collection = SizeRestricted::<i32, _, 1, 4>::new(vec![1, 3, 5]).unwrap();
+        let index = collection.partition_point(|val| *val < 4);
+        assert_eq!(index, 2);
+
+        collection.push(4).unwrap();
+        collection.view_mut()[index..].rotate_right(1);
+        assert_eq!(collection.inner(), &vec![1, 3, 4, 5]);
+
+        assert_eq!(collection.partition_point(|val| *val < 2), 1);
+        assert_eq!(collection.push(2), Err((SizeRangeError::TooLarge, 2)));
+    }
+
+    #[test]
+    fn windows_chunks() {
+        let collection =
+            SizeRestricted::<i32, _, 10, 10>::new((0..10).collect::<Vec<_>>()).unwrap();
+
+        assert_eq!(collection.windows(3).count(), 8);
+        assert_eq!(collection.windows(3).last(), Some(&[7, 8, 9][..]));
+
+        assert_eq!(collection.chunks(4).count(), 3);
+        assert_eq!(collection.chunks(4).nth(1), Some(&[4, 5, 6, 7][..]));
+        assert_eq!(collection.chunks(4).last(), Some(&[8, 9][..]));
+    }
+
+    #[test]
+    fn chunks_exact() {
+        let mut collection = ExactSized::<u8, _, 10>::new((0..10).collect::<Vec<_>>()).unwrap();
+        assert_eq!(collection.chunks_exact(4).count(), 2);
+        assert_eq!(collection.chunks_exact(4).remainder(), &[8, 9]);
+
+        let mut chunks = collection.chunks_exact_mut(4);
+        for chunk in &mut chunks {
+            chunk.reverse();
+        }
+        chunks.into_remainder()[0] = 0;
+        assert_eq!(collection.as_slice(), &[3, 2, 1, 0, 7, 6, 5, 4, 0, 9]);
+    }
+
+    #[test]
+    fn split_at() {
+        let mut collection = SizeRestricted::<i32, _, 1, 10>::new(vec![1, 2, 3, 4, 5]).unwrap();
+        let (left, right) = collection.split_at(2);
+        assert_eq!([left, right].concat(), collection.inner().as_slice());
+
+        let (left, right) = collection.split_at(0);
+        assert!(left.is_empty());
+        assert_eq!(right, &[1, 2, 3, 4, 5]);
+
+        let (left, right) = collection.split_at_mut(3);
+        left[0] = right[1];
+        assert_eq!(collection.inner(), &vec![5, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn swap_with() {
+        let mut front = SizeRestricted::<i32, _, 1, 4>::new(vec![1, 2]).unwrap();
+        let mut back = SizeRestricted::<i32, _, 1, 4>::new(vec![3, 4, 5, 6]).unwrap();
+        front.swap_with(&mut back);
+        assert_eq!(front.inner(), &vec![3, 4, 5, 6]);
+        assert_eq!(back.inner(), &vec![1, 2]);
+        assert!(SizeRestricted::<i32, _, 1, 4>::check_fit(front.inner()).is_ok());
+        assert!(SizeRestricted::<i32, _, 1, 4>::check_fit(back.inner()).is_ok());
+    }
+
+    #[test]
+    fn keep_first_last() {
+        use alloc::collections::VecDeque;
+
+        let mut collection = SizeRestricted::<i32, _, 2, 10>::new(vec![1, 2, 3, 4, 5]).unwrap();
+        collection.keep_first(8);
+        assert_eq!(collection.inner(), &vec![1, 2, 3, 4, 5]);
+        collection.keep_first(3);
+        assert_eq!(collection.inner(), &vec![1, 2, 3]);
+        collection.keep_first(0);
+        assert_eq!(collection.inner(), &vec![1, 2]);
+
+        let mut collection =
+            SizeRestricted::<i32, _, 2, 10>::new(VecDeque::from([1, 2, 3, 4, 5])).unwrap();
+        collection.keep_last(8);
+        assert_eq!(collection.len(), 5);
+        collection.keep_last(3);
+        assert_eq!(collection.inner(), &VecDeque::from([3, 4, 5]));
+        collection.keep_last(1);
+        assert_eq!(collection.inner(), &VecDeque::from([4, 5]));
+    }
+
+    #[test]
+    fn debug_bounds() {
+        use alloc::format;
+
+        let collection = SizeRestricted::<i32, _, 1, 10>::new(vec![1, 2, 3]).unwrap();
+        assert_eq!(
+            format!("{collection:?}"),
+            "SizeRestricted<1..=10> [1, 2, 3]"
+        );
+        assert!(format!("{collection:#?}").starts_with("SizeRestricted<1..=10> [\n"));
+    }
+
+    #[test]
+    fn rchunks() {
+        let mut collection = SizeRestricted::<i32, _, 1, 10>::new(vec![1, 2, 3, 4, 5]).unwrap();
+        let chunks = collection.rchunks(2).collect::<Vec<_>>();
+        assert_eq!(chunks, [&[4, 5][..], &[2, 3], &[1]]);
+        assert!(collection.rchunks(2).eq(collection.as_slice().rchunks(2)));
+
+        for chunk in collection.rchunks_mut(2) {
+            chunk.reverse();
+        }
+        assert_eq!(collection.inner(), &vec![1, 3, 2, 5, 4]);
+    }
+
+    #[test]
+    fn extend_saturating() {
+        let mut collection = SizeRestricted::<i32, _, 1, 5>::new(vec![1]).unwrap();
+        assert_eq!(collection.extend_saturating([2, 3]), 0);
+        assert_eq!(collection.inner(), &vec![1, 2, 3]);
+
+        assert_eq!(collection.extend_saturating(vec![4, 5, 6, 7, 8]), 3);
+        assert_eq!(collection.inner(), &vec![1, 2, 3, 4, 5]);
+        assert_eq!(collection.extend_saturating(core::iter::empty()), 0);
+
+        let mut collection = SizeRestricted::<i32, _, 0, 3>::new(vec![]).unwrap();
+        assert_eq!(collection.extend_saturating((0..).take(1000)), 997);
+        assert_eq!(collection.inner(), &vec![0, 1, 2]);
+
+        let mut collection = SizeRestricted::<i32, _, 0, 3>::new(vec![]).unwrap();
+        let mut iter = (0..10).filter(|val| val % 2 == 0);
+        assert_eq!(collection.extend_saturating(iter.by_ref()), 2);
+        assert_eq!(iter.next(), None);
+        assert_eq!(collection.inner(), &vec![0, 2, 4]);
+    }
+
+    #[test]
+    fn as_ptr() {
+        let mut collection = SizeRestricted::<i32, _, 1, 10>::new(vec![1, 2, 3]).unwrap();
+        let ptr = collection.as_ptr();
+        // SAFETY: The pointer is valid for len elements and the collection isn't mutated
+        let read = unsafe { core::slice::from_raw_parts(ptr, collection.len()) };
+        assert_eq!(read, collection.view());
+
+        let ptr = collection.as_mut_ptr();
+        // SAFETY: The pointer is valid for len elements and the collection isn't used otherwise
+        unsafe { ptr.add(1).write(4) };
+        assert_eq!(collection.view(), &[1, 4, 3]);
+    }
+
+    /// Iterate over a borrowed and mutably borrowed [`SizeRestricted`] with 3 elements
+    fn iterate_borrowed<C>(mut collection: SizeRestricted<i32, C, 0, 10>)
+    where
+        C: LinearSizedCollection<i32>
+            + for<'a> crate::Iterable<'a, i32>
+            + for<'a> crate::IterableMut<'a, i32>,
+    {
+        for val in &mut collection {
+            *val *= 2;
+        }
+
+        let mut sum = 0;
+        for val in &collection {
+            sum += val;
+        }
+        assert_eq!(sum, 12);
+    }
+
+    #[test]
+    fn into_iter_borrowed() {
+        use alloc::{
+            boxed::Box,
+            collections::{LinkedList, VecDeque},
+        };
+
+        iterate_borrowed(SizeRestricted::new(vec![1, 2, 3]).unwrap());
+        iterate_borrowed(SizeRestricted::new(VecDeque::from([1, 2, 3])).unwrap());
+        iterate_borrowed(SizeRestricted::new(LinkedList::from([1, 2, 3])).unwrap());
+        iterate_borrowed(SizeRestricted::new(Box::from([1, 2, 3])).unwrap());
+
+        let mut inline = crate::InlineVec::<_, 3>::new();
+        [1, 2, 3].into_iter().for_each(|val| inline.push(val));
+        iterate_borrowed(SizeRestricted::new(inline).unwrap());
+
+        #[cfg(feature = "gap_buffer")]
+        {
+            let mut buffer = crate::GapBuffer::new();
+            [1, 3].into_iter().for_each(|val| buffer.insert(val));
+            buffer.move_gap(1);
+            buffer.insert(2);
+            iterate_borrowed(SizeRestricted::new(buffer).unwrap());
+        }
+
+        #[cfg(feature = "impl_im")]
+        iterate_borrowed(SizeRestricted::new(im::Vector::from(vec![1, 2, 3])).unwrap());
+
+        #[cfg(feature = "impl_tinyvec")]
+        iterate_borrowed(SizeRestricted::new(tinyvec::tiny_vec!([i32; 4] => 1, 2, 3)).unwrap());
+    }
+
+    #[test]
+    fn chunk_into() {
+        let collection = SizeRestricted::<i32, Vec<_>, 1, 10>::from_fn(10, |i| i as i32).unwrap();
+        let chunks = collection.chunk_into::<3>();
+        assert_eq!(
+            chunks.iter().map(|chunk| chunk.len()).collect::<Vec<_>>(),
+            [3, 3, 3, 1]
+        );
+        assert_eq!(chunks[1].inner(), &vec![3, 4, 5]);
+        assert_eq!(chunks[3].inner(), &vec![9]);
+
+        let empty = SizeRestricted::<i32, Vec<_>, 0, 10>::new(vec![]).unwrap();
+        assert!(empty.chunk_into::<3>().is_empty());
+    }
+
+    #[test]
+    fn sum_min_max() {
+        use alloc::collections::LinkedList;
+
+        let collection =
+            SizeRestricted::<i32, _, 0, 10>::new(LinkedList::from([3, 1, 4, 1])).unwrap();
+        assert_eq!(collection.sum::<i32>(), 9);
+        assert_eq!(collection.min_element(), Some(&1));
+        assert_eq!(collection.max_element(), Some(&4));
+
+        let empty = SizeRestricted::<i32, _, 0, 10>::new(LinkedList::new()).unwrap();
+        assert_eq!(empty.sum::<i32>(), 0);
+        assert_eq!(empty.min_element(), None);
+        assert_eq!(empty.max_element(), None);
+
+        let collection = NonEmpty::<i32, Vec<_>>::new(vec![5, -2, 7]).unwrap();
+        assert_eq!(collection.min_nonempty(), &-2);
+        assert_eq!(collection.max_nonempty(), &7);
+    }
+
+    #[test]
+    fn set_len_unchecked_default_drops() {
+        use alloc::{collections::LinkedList, rc::Rc};
+
+        let counted = Rc::new(());
+        let list = LinkedList::from([Rc::clone(&counted), Rc::clone(&counted)]);
+        let mut collection = SizeRestricted::<_, _, 1, 2>::new(list).unwrap();
+        // SAFETY: 1 is in the bounds and the first element is initialized
+        unsafe { collection.set_len_unchecked(1) };
+        assert_eq!(collection.len(), 1);
+        assert_eq!(Rc::strong_count(&counted), 2);
+    }
+
+    /// A collection which shares its elements, the length can be changed through another handle
+    #[derive(Debug, Default)]
+    struct Shared(alloc::rc::Rc<core::cell::RefCell<Vec<i32>>>);
+
+    impl LinearSizedCollection<i32> for Shared {
+        fn len(&self) -> usize {
+            self.0.borrow().len()
+        }
+
+        fn push(&mut self, val: i32) {
+            self.0.borrow_mut().push(val);
+        }
+
+        fn pop(&mut self) -> Option<i32> {
+            self.0.borrow_mut().pop()
+        }
+
+        fn reserve(&mut self, additional: usize) {
+            self.0.borrow_mut().reserve(additional);
+        }
+
+        fn count_retained<F: FnMut(&i32) -> bool>(&mut self, mut f: F) -> usize {
+            self.0.borrow().iter().filter(|val| f(val)).count()
+        }
+    }
+
+    #[test]
+    fn validate() {
+        let collection = SizeRestricted::<i32, _, 1, 3>::new(vec![1, 2]).unwrap();
+        assert_eq!(collection.validate(), Ok(()));
+        collection.debug_assert_valid();
+
+        let shared = Shared::default();
+        let handle = alloc::rc::Rc::clone(&shared.0);
+        let collection = SizeRestricted::<i32, _, 0, 3>::new(shared).unwrap();
+        handle.borrow_mut().extend([1, 2, 3, 4]);
+        assert_eq!(collection.validate(), Err(SizeRangeError::TooLarge));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "has to fit in its size restriction")]
+    fn debug_assert_valid() {
+        let shared = Shared::default();
+        let handle = alloc::rc::Rc::clone(&shared.0);
+        let collection = SizeRestricted::<i32, _, 0, 3>::new(shared).unwrap();
+        handle.borrow_mut().extend([1, 2, 3, 4]);
+        collection.debug_assert_valid();
+    }
+
+    #[test]
+    fn get_or_extend() {
+        let mut collection = SizeRestricted::<i32, _, 1, 5>::new(vec![1, 2]).unwrap();
+        *collection.get_or_extend(1, || 0).unwrap() = 3;
+        assert_eq!(collection.inner(), &vec![1, 3]);
+
+        *collection.get_or_extend(3, || 0).unwrap() = 4;
+        assert_eq!(collection.inner(), &vec![1, 3, 0, 4]);
+
+        assert_eq!(
+            collection.get_or_extend(5, || 0),
+            Err(SizeRangeError::TooLarge)
+        );
+        assert_eq!(collection.inner(), &vec![1, 3, 0, 4]);
+    }
+
+    #[test]
+    fn from_inner_unchecked() {
+        // SAFETY: The collection has 3 elements
+        let collection =
+            unsafe { SizeRestricted::<i32, _, 1, 3>::from_inner_unchecked(vec![1, 2, 3]) };
+        assert_eq!(
+            collection,
+            SizeRestricted::<i32, _, 1, 3>::new(vec![1, 2, 3]).unwrap()
+        );
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "from_inner_unchecked has to keep the size restriction")]
+    fn from_inner_unchecked_misuse() {
+        // SAFETY: Not safe, the debug assertion catches the misuse
+        let _collection = unsafe { SizeRestricted::<i32, _, 1, 3>::from_inner_unchecked(vec![]) };
+    }
+
+    #[test]
+    fn restricted_subrange() {
+        let collection = SizeRestricted::<i32, _, 1, 10>::new(vec![1, 2, 3, 4, 5]).unwrap();
+        assert_eq!(collection.subslice(1..3), &[2, 3]);
+
+        let sub = collection.restricted_subrange::<2, 3>(1..4).unwrap();
+        assert_eq!(sub.as_slice(), &[2, 3, 4]);
+        assert_eq!(sub.len(), 3);
+
+        assert_eq!(
+            collection.restricted_subrange::<2, 3>(0..5),
+            Err(SizeRangeError::TooLarge)
+        );
+        assert_eq!(
+            collection.restricted_subrange::<2, 3>(2..3),
+            Err(SizeRangeError::TooSmall)
+        );
+    }
+
+    #[test]
+    fn with_min_fill() {
+        /// A type without a [`Default`] implementation
+        #[derive(Debug, PartialEq)]
+        struct Placeholder(u8);
+
+        let collection = NonEmpty::<Placeholder, Vec<_>>::with_min_fill(|| Placeholder(7));
+        assert_eq!(collection.inner(), &vec![Placeholder(7)]);
+
+        let mut i = 0;
+        let collection = SizeRestricted::<i32, Vec<_>, 3, 5>::with_min_fill(|| {
+            i += 1;
+            i
+        });
+        assert_eq!(collection.inner(), &vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn cycle() {
+        let wavetable = ExactSized::<i32, Vec<_>, 3>::new(vec![0, 1, -1]).unwrap();
+        assert_eq!(
+            wavetable.cycle().copied().take(6).collect::<Vec<_>>(),
+            [0, 1, -1, 0, 1, -1]
+        );
+    }
+
+    #[test]
+    fn select() {
+        let mut collection = SizeRestricted::<i32, _, 2, 10>::new(vec![1, 2, 3, 4, 5]).unwrap();
+        collection.select(&[0, 2, 4]).unwrap();
+        assert_eq!(collection.inner(), &vec![1, 3, 5]);
+
+        collection.select(&[2, 0]).unwrap();
+        assert_eq!(collection.inner(), &vec![5, 1]);
+
+        assert_eq!(
+            collection.select(&[1]),
+            Err(SelectError::Size(SizeRangeError::TooSmall))
+        );
+        assert_eq!(collection.inner(), &vec![5, 1]);
+    }
+
+    #[test]
+    fn select_invalid_indices() {
+        let mut collection = SizeRestricted::<i32, _, 0, 10>::new(vec![1, 2, 3]).unwrap();
+        assert_eq!(collection.select(&[0, 3]), Err(SelectError::OutOfBounds(3)));
+        assert_eq!(
+            collection.select(&[2, 1, 2]),
+            Err(SelectError::Duplicate(2))
+        );
+        assert_eq!(collection.select(&[1, 1]), Err(SelectError::Duplicate(1)));
+        assert_eq!(collection.inner(), &vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn eq_inner() {
+        use alloc::{
+            boxed::Box,
+            collections::{LinkedList, VecDeque},
+            string::String,
+        };
+
+        let collection = SizeRestricted::<i32, _, 1, 5>::new(vec![1, 2]).unwrap();
+        assert_eq!(collection, vec![1, 2]);
+        assert_ne!(collection, vec![1]);
+
+        let collection = SizeRestricted::<i32, _, 1, 5>::new(VecDeque::from([1, 2])).unwrap();
+        assert_eq!(collection, VecDeque::from([1, 2]));
+
+        let collection = SizeRestricted::<i32, _, 1, 5>::new(LinkedList::from([1, 2])).unwrap();
+        assert_eq!(collection, LinkedList::from([1, 2]));
+
+        let collection = SizeRestricted::<i32, Box<[_]>, 1, 5>::new(Box::from([1, 2])).unwrap();
+        assert_eq!(collection, Box::<[_]>::from([1, 2]));
+
+        let collection = SizeRestricted::<char, _, 1, 5>::new(String::from("ab")).unwrap();
+        assert_eq!(collection, String::from("ab"));
+    }
+
+    #[test]
+    fn fold() {
+        use alloc::collections::LinkedList;
+        use core::ops::ControlFlow;
+
+        let collection =
+            SizeRestricted::<i32, _, 1, 10>::new(LinkedList::from([1, 2, 3, 4])).unwrap();
+        assert_eq!(collection.fold(0, |acc, val| acc + val), 10);
+        assert_eq!(
+            collection.try_fold(0, |acc, val| ControlFlow::<i32, _>::Continue(acc + val)),
+            ControlFlow::Continue(10)
+        );
+
+        let mut visited = 0;
+        let result = collection.try_fold(0, |acc, &val| {
+            visited += 1;
+            if acc + val > 3 {
+                ControlFlow::Break(acc)
+            } else {
+                ControlFlow::Continue(acc + val)
+            }
+        });
+        assert_eq!(result, ControlFlow::Break(3));
+        assert_eq!(visited, 3);
+    }
+
+    #[test]
+    fn move_to_front() {
+        use alloc::collections::VecDeque;
+
+        let mut collection = SizeRestricted::<i32, _, 1, 5>::new(vec![1, 2, 3, 4]).unwrap();
+        collection.move_to_front(2);
+        assert_eq!(collection.inner(), &vec![3, 1, 2, 4]);
+        collection.move_to_front(0);
+        assert_eq!(collection.inner(), &vec![3, 1, 2, 4]);
+
+        let mut lru = SizeRestricted::<i32, _, 1, 5>::new(VecDeque::from([1, 2, 3, 4])).unwrap();
+        lru.move_to_front(3);
+        assert_eq!(lru.inner(), &VecDeque::from([4, 1, 2, 3]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn move_to_front_out_of_bounds() {
+        let mut collection = SizeRestricted::<i32, _, 1, 5>::new(vec![1, 2]).unwrap();
+        collection.move_to_front(2);
+    }
+
+    #[test]
+    fn try_into_exact() {
+        let collection = SizeRestricted::<i32, _, 1, 5>::new(vec![1, 2, 3]).unwrap();
+        let exact: ExactSized<i32, Vec<_>, 3> = collection.clone().try_into_exact().unwrap();
+        assert_eq!(exact.inner(), &vec![1, 2, 3]);
+        assert_eq!(
+            collection.try_into_exact::<4>().unwrap_err(),
+            (SizeRangeError::TooSmall, vec![1, 2, 3])
+        );
+
+        let flexible = exact.into_flexible::<0, 10>();
+        assert_eq!(flexible.into_parts(), (vec![1, 2, 3], 0, 10));
+    }
+
+    #[test]
+    fn singleton() {
+        use alloc::collections::LinkedList;
+
+        let collection = NonEmpty::<i32, Vec<_>>::singleton(3);
+        assert_eq!(collection.len(), 1);
+        assert_eq!(collection.first(), Some(&3));
+
+        let collection = NonEmpty::<i32, LinkedList<_>>::singleton(4);
+        assert_eq!(collection, LinkedList::from([4]));
+    }
+
+    #[test]
+    fn dedup_consecutive() {
+        let mut collection =
+            SizeRestricted::<i32, _, 1, 10>::new(vec![1, 1, 2, 2, 2, 3, 1]).unwrap();
+        collection.dedup_consecutive(|a, b| a == b).unwrap();
+        assert_eq!(collection.inner(), &vec![1, 2, 3, 1]);
+
+        let mut collection = SizeRestricted::<i32, _, 1, 10>::new(vec![1]).unwrap();
+        collection.dedup_consecutive(|_, _| true).unwrap();
+        assert_eq!(collection.inner(), &vec![1]);
+
+        let mut collection = SizeRestricted::<i32, _, 3, 10>::new(vec![1, 1, 2, 2, 2]).unwrap();
+        assert_eq!(
+            collection.dedup_consecutive(|a, b| a == b),
+            Err(SizeRangeError::TooSmall)
+        );
+        assert_eq!(collection.inner(), &vec![1, 1, 2, 2, 2]);
+    }
+
+    #[test]
+    fn dedup_consecutive_stateful() {
+        let mut collection = SizeRestricted::<i32, _, 2, 10>::new(vec![1, 2, 3, 4]).unwrap();
+        let mut calls = 0;
+        assert_eq!(
+            collection.dedup_consecutive(|_, _| {
+                calls += 1;
+                calls <= 3
+            }),
+            Err(SizeRangeError::TooSmall)
+        );
+        assert_eq!(calls, 3);
+        assert_eq!(collection.inner(), &vec![1, 2, 3, 4]);
+
+        let mut calls = 0;
+        collection
+            .dedup_consecutive(|_, _| {
+                calls += 1;
+                calls <= 2
+            })
+            .unwrap();
+        assert_eq!(calls, 3);
+        assert_eq!(collection.inner(), &vec![1, 4]);
+
+        let mut stack = SizeRestricted::<i32, _, 2, 10>::new(Stack(vec![1, 1, 2, 3, 3])).unwrap();
+        let mut calls = 0;
+        stack
+            .dedup_consecutive(|a, b| {
+                calls += 1;
+                a == b
+            })
+            .unwrap();
+        assert_eq!(calls, 4);
+        assert_eq!(stack.inner().0, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn reserve_hint() {
+        let mut collection = SizeRestricted::<i32, _, 0, 100>::new(vec![]).unwrap();
+        collection.reserve_hint(64);
+        let capacity = collection.inner().capacity();
+        let ptr = collection.inner().as_ptr();
+        assert!(capacity >= 64);
+
+        for i in 0..64 {
+            collection.push(i).unwrap();
+        }
+        assert_eq!(collection.inner().capacity(), capacity);
+        assert_eq!(collection.inner().as_ptr(), ptr);
+
+        let mut collection = SizeRestricted::<i32, _, 0, 8>::new(vec![]).unwrap();
+        collection.reserve_hint(usize::MAX);
+        assert!(collection.inner().capacity() >= 8);
+    }
+
+    #[test]
+    fn take() {
+        let mut collection = SizeRestricted::<i32, _, 2, 5>::new(vec![1, 2, 3]).unwrap();
+        assert_eq!(collection.take(), vec![1, 2, 3]);
+        assert_eq!(collection.len(), 2);
+        assert_eq!(collection.inner(), &vec![0, 0]);
+    }
+
+    #[test]
+    fn resize() {
+        let mut collection = SizeRestricted::<i32, _, 2, 5>::new(vec![1, 2, 3]).unwrap();
+        collection.resize(5).unwrap();
+        assert_eq!(collection.inner(), &vec![1, 2, 3, 0, 0]);
+        collection.resize(2).unwrap();
+        assert_eq!(collection.inner(), &vec![1, 2]);
+
+        collection.resize_with(4, || 7).unwrap();
+        assert_eq!(collection.inner(), &vec![1, 2, 7, 7]);
+
+        assert_eq!(collection.resize(6), Err(SizeRangeError::TooLarge));
+        assert_eq!(
+            collection.resize_with(1, || 7),
+            Err(SizeRangeError::TooSmall)
+        );
+        assert_eq!(collection.inner(), &vec![1, 2, 7, 7]);
+    }
+
+    #[test]
+    fn for_each_window_mut() {
+        let mut collection = SizeRestricted::<i32, _, 1, 10>::new(vec![0, 9, 0, 9, 0]).unwrap();
+        let mut windows = 0;
+        collection.for_each_window_mut(3, |window| {
+            windows += 1;
+            window[1] = (window[0] + window[1] + window[2]) / 3;
+        });
+        assert_eq!(windows, 3);
+        assert_eq!(collection.inner(), &vec![0, 3, 4, 4, 0]);
+
+        collection.for_each_window_mut(6, |_| unreachable!());
+        collection.for_each_window_mut(5, |window| window.reverse());
+        assert_eq!(collection.inner(), &vec![0, 4, 4, 3, 0]);
+    }
+
+    #[test]
+    #[cfg(feature = "impl_arbitrary")]
+    fn arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let data = (0..=255).cycle().take(4096).collect::<Vec<u8>>();
+        for offset in 0..64 {
+            let mut u = Unstructured::new(&data[offset..]);
+            let collection = SizeRestricted::<u8, Vec<_>, 2, 10>::arbitrary(&mut u).unwrap();
+            assert_eq!(collection.validate(), Ok(()));
+
+            let collection = NonEmpty::<u8, Vec<_>>::arbitrary(&mut u).unwrap();
+            assert_eq!(collection.validate(), Ok(()));
+            assert!(collection.len() <= 257);
+        }
+
+        let mut u = Unstructured::new(&[]);
+        let collection = ExactSized::<u8, Vec<_>, 3>::arbitrary(&mut u).unwrap();
+        assert_eq!(collection.len(), 3);
+
+        for offset in 0..64 {
+            let mut u = Unstructured::new(&data[offset..]);
+            let generated =
+                SizeRestricted::<bool, alloc::collections::BTreeSet<_>, 3, 10>::arbitrary(&mut u);
+            assert_eq!(generated.unwrap_err(), arbitrary::Error::IncorrectFormat);
+
+            #[cfg(feature = "impl_tinyvec")]
+            {
+                let collection =
+                    NonEmpty::<u8, tinyvec::ArrayVec<[u8; 4]>>::arbitrary(&mut u).unwrap();
+                assert!(collection.len() <= 4);
+            }
+        }
+    }
+
+    #[test]
+    fn iter_step() {
+        let collection = SizeRestricted::<i32, _, 1, 10>::new(vec![0, 1, 2, 3, 4, 5]).unwrap();
+        assert_eq!(collection.iter_step(2).collect::<Vec<_>>(), [&0, &2, &4]);
+        assert_eq!(collection.iter_step(4).collect::<Vec<_>>(), [&0, &4]);
+    }
+
+    #[test]
+    fn assert_bounds() {
+        crate::assert_bounds!(NonEmpty<i32, Vec<i32>>, min = 1, max = usize::MAX);
+        crate::assert_bounds!(ExactSized<i32, Vec<i32>, 4>, min = 4, max = 4);
+        crate::assert_bounds!(SizeRestricted<i32, Vec<i32>, 0, 3>, min = 0, max = 3);
+    }
+
+    #[test]
+    fn split_half() {
+        let collection = SizeRestricted::<i32, _, 2, 10>::new(vec![1, 2, 3, 4]).unwrap();
+        let (front, back) = collection.split_half();
+        assert_eq!(front.inner(), &vec![1, 2]);
+        assert_eq!(back.inner(), &vec![3, 4]);
+
+        let collection = SizeRestricted::<i32, _, 1, 10>::new(vec![1, 2, 3, 4, 5]).unwrap();
+        let (front, back) = collection.split_half();
+        assert_eq!(front.len(), 2);
+        assert_eq!(back.len(), 3);
+        let mut reassembled = front.into_inner();
+        reassembled.append(&mut back.into_inner());
+        assert_eq!(reassembled, vec![1, 2, 3, 4, 5]);
+
+        let collection = SizeRestricted::<i32, _, 1, 10>::new(vec![1]).unwrap();
+        let (front, back) = collection.split_half();
+        assert!(front.is_empty());
+        assert_eq!(back.inner(), &vec![1]);
+    }
+
+    #[test]
+    fn get_or() {
+        let collection = SizeRestricted::<i32, _, 1, 5>::new(vec![1, 2, 3]).unwrap();
+        assert_eq!(collection.get_or(1, &0), &2);
+        assert_eq!(collection.get_or(3, &0), &0);
+        assert_eq!(collection.get_or(usize::MAX, &-1), &-1);
+
+        let fallback = 42;
+        assert_eq!(collection.get_or_else(2, || unreachable!()), &3);
+        assert_eq!(collection.get_or_else(5, || &fallback), &42);
+    }
+
+    #[test]
+    fn extend() {
+        let mut collection = SizeRestricted::<i32, _, 0, 4>::new(vec![1]).unwrap();
+        collection.extend(2..);
+        assert_eq!(collection.inner(), &vec![1, 2, 3, 4]);
+
+        let other = SizeRestricted::<u8, _, 1, 10>::new(vec![1u8, 2, 3]).unwrap();
+        let mut collection = SizeRestricted::<u8, _, 1, 5>::new(vec![0u8]).unwrap();
+        collection.extend(other.iter());
+        assert_eq!(collection.inner(), &vec![0, 1, 2, 3]);
+        collection.extend(other.iter());
+        assert_eq!(collection.inner(), &vec![0, 1, 2, 3, 1]);
+    }
+
+    #[test]
+    fn drain_to_min() {
+        let mut collection = SizeRestricted::<i32, _, 2, 5>::new(vec![1, 2, 3, 4, 5]).unwrap();
+        assert_eq!(collection.drain_to_min(), vec![5, 4, 3]);
+        assert_eq!(collection.inner(), &vec![1, 2]);
+        assert!(collection.drain_to_min().is_empty());
+
+        let mut collection = SizeRestricted::<i32, _, 0, 3>::new(vec![1, 2]).unwrap();
+        assert_eq!(collection.drain_to_min(), vec![2, 1]);
+        assert!(collection.is_empty());
+    }
+
+    #[test]
+    fn as_chunks() {
+        let collection = ExactSized::<u8, _, 10>::new((0u8..10).collect::<Vec<_>>()).unwrap();
+        let (chunks, remainder) = collection.as_chunks::<4>();
+        assert_eq!(chunks, &[[0, 1, 2, 3], [4, 5, 6, 7]]);
+        assert_eq!(remainder, &[8, 9]);
+
+        let (chunks, remainder) = collection.as_chunks::<5>();
+        assert_eq!(chunks.len(), 2);
+        assert!(remainder.is_empty());
+
+        let (chunks, remainder) = collection.as_chunks::<16>();
+        assert!(chunks.is_empty());
+        assert_eq!(remainder.len(), 10);
     }
 }
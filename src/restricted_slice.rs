@@ -0,0 +1,57 @@
+//! A borrowed size restricted slice
+
+use core::ops::Deref;
+
+use crate::SizeRangeError;
+
+/// A borrowed slice whichs length is between `MIN` and `MAX`, this is the borrowed counterpart of a
+/// [`SizeRestricted`](crate::SizeRestricted) collection viewed as slice.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub struct RestrictedSlice<'a, T, const MIN: usize, const MAX: usize> {
+    /// The slice whichs length is restricted
+    slice: &'a [T],
+}
+
+impl<'a, T, const MIN: usize, const MAX: usize> RestrictedSlice<'a, T, MIN, MAX> {
+    /// The min length
+    pub const MIN: usize = MIN;
+    /// The max length
+    pub const MAX: usize = MAX;
+
+    /// Create a [`RestrictedSlice`] while ensuring that the slice has a correct length.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SizeRangeError::TooLarge`] if the slice is longer than `MAX` and [`SizeRangeError::TooSmall`] if it
+    /// is shorter than `MIN`.
+    pub fn new(slice: &'a [T]) -> Result<Self, SizeRangeError> {
+        if slice.len() > MAX {
+            Err(SizeRangeError::TooLarge)
+        } else if slice.len() < MIN {
+            Err(SizeRangeError::TooSmall)
+        } else {
+            Ok(Self { slice })
+        }
+    }
+
+    /// Get the underlying slice
+    pub fn as_slice(&self) -> &'a [T] {
+        self.slice
+    }
+}
+
+impl<T, const MIN: usize, const MAX: usize> Clone for RestrictedSlice<'_, T, MIN, MAX> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, const MIN: usize, const MAX: usize> Copy for RestrictedSlice<'_, T, MIN, MAX> {}
+
+impl<T, const MIN: usize, const MAX: usize> Deref for RestrictedSlice<'_, T, MIN, MAX> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        self.slice
+    }
+}
@@ -0,0 +1,111 @@
+//! A size restricted ring buffer which evicts its oldest elements
+
+use core::ops::{Deref, DerefMut};
+
+use crate::{LinearSizedDeque, SizeRangeError, SizeRestricted};
+
+/// A [`SizeRestricted`] collection used as a ring buffer. Pushing to a full ring buffer with
+/// [`push_evicting`](RingRestricted::push_evicting) evicts the element at the front.
+///
+/// A ring buffer with a `MAX` of 0 could never hold an element, creating one fails to compile:
+///
+/// ```compile_fail
+/// # use std::collections::VecDeque;
+/// # use srestricted::RingRestricted;
+/// let ring = RingRestricted::<i32, _, 0, 0>::new(VecDeque::new());
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Hash)]
+pub struct RingRestricted<T, C: LinearSizedDeque<T>, const MIN: usize, const MAX: usize> {
+    /// The restricted collection used as ring buffer
+    restricted: SizeRestricted<T, C, MIN, MAX>,
+}
+
+impl<T, C: LinearSizedDeque<T>, const MIN: usize, const MAX: usize> RingRestricted<T, C, MIN, MAX> {
+    /// A validity check that the ring buffer can hold at least one element
+    const NON_ZERO: bool = {
+        assert!(
+            MAX >= 1,
+            "The MAX size of a RingRestricted must be at least 1"
+        );
+        true
+    };
+
+    /// Create a [`RingRestricted`] while ensuring that the given collection has a correct size.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the collection doesn't fit in the size restriction (see [`SizeRestricted::new`])
+    pub fn new(collection: C) -> Result<Self, (SizeRangeError, C)> {
+        SizeRestricted::new(collection).map(Self::from_restricted)
+    }
+
+    /// Use an existing [`SizeRestricted`] collection as ring buffer
+    pub fn from_restricted(restricted: SizeRestricted<T, C, MIN, MAX>) -> Self {
+        assert!(Self::NON_ZERO);
+        Self { restricted }
+    }
+
+    /// Push `val` to the end of the ring buffer. If the ring buffer is full the element at the front is
+    /// evicted and returned.
+    pub fn push_evicting(&mut self, val: T) -> Option<T> {
+        let collection = &mut self.restricted.collection;
+        let evicted = if collection.len() >= MAX {
+            collection.pop_front()
+        } else {
+            None
+        };
+
+        collection.push(val);
+        evicted
+    }
+
+    /// Unwraps the [`SizeRestricted`] collection used as ring buffer
+    pub fn into_restricted(self) -> SizeRestricted<T, C, MIN, MAX> {
+        self.restricted
+    }
+}
+
+impl<T, C: LinearSizedDeque<T>, const MIN: usize, const MAX: usize> Deref
+    for RingRestricted<T, C, MIN, MAX>
+{
+    type Target = SizeRestricted<T, C, MIN, MAX>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.restricted
+    }
+}
+
+impl<T, C: LinearSizedDeque<T>, const MIN: usize, const MAX: usize> DerefMut
+    for RingRestricted<T, C, MIN, MAX>
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.restricted
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod test {
+    use alloc::collections::VecDeque;
+
+    use crate::RingRestricted;
+
+    #[test]
+    fn push_evicting() {
+        let mut ring = RingRestricted::<i32, _, 0, 4>::new(VecDeque::new()).unwrap();
+        for i in 0..4 {
+            assert_eq!(ring.push_evicting(i), None);
+        }
+
+        assert_eq!(ring.push_evicting(4), Some(0));
+        assert_eq!(ring.push_evicting(5), Some(1));
+        assert_eq!(ring.len(), 4);
+        assert_eq!(ring.inner(), &VecDeque::from([2, 3, 4, 5]));
+    }
+
+    #[test]
+    fn push_evicting_exact() {
+        let mut ring = RingRestricted::<i32, _, 2, 2>::new(VecDeque::from([1, 2])).unwrap();
+        assert_eq!(ring.push_evicting(3), Some(1));
+        assert_eq!(ring.inner(), &VecDeque::from([2, 3]));
+    }
+}
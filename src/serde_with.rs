@@ -0,0 +1,113 @@
+//! Functions to use with `#[serde(with = "...")]` on [`SizeRestricted`](crate::SizeRestricted) fields
+
+/// Serialize a [`SizeRestricted`] collection together with its bounds as `{ "min": MIN, "max": MAX, "data": [...] }`.
+/// Deserializing fails if the bounds don't match the bounds of the field or the data doesn't fit in them.
+///
+/// ```
+/// # use srestricted::NonEmpty;
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Message {
+///     #[serde(with = "srestricted::serde_with::bounded")]
+///     recipients: NonEmpty<u32, Vec<u32>>,
+/// }
+/// ```
+pub mod bounded {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::{LinearSizedCollection, SizeRestricted};
+
+    /// The serialized form of a collection with its bounds
+    #[derive(Serialize)]
+    struct BoundedRef<'a, C> {
+        /// The MIN bound
+        min: usize,
+        /// The MAX bound
+        max: usize,
+        /// The inner collection
+        data: &'a C,
+    }
+
+    /// The deserialized form of a collection with its bounds
+    #[derive(Deserialize)]
+    struct Bounded<C> {
+        /// The MIN bound
+        min: usize,
+        /// The MAX bound
+        max: usize,
+        /// The inner collection
+        data: C,
+    }
+
+    /// Serialize a [`SizeRestricted`] collection with its bounds
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the inner collection can't be serialized
+    pub fn serialize<T, C, S, const MIN: usize, const MAX: usize>(
+        restricted: &SizeRestricted<T, C, MIN, MAX>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        C: LinearSizedCollection<T> + Serialize,
+        S: Serializer,
+    {
+        BoundedRef {
+            min: MIN,
+            max: MAX,
+            data: restricted.inner(),
+        }
+        .serialize(serializer)
+    }
+
+    /// Deserialize a [`SizeRestricted`] collection with its bounds
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the serialized bounds don't match `MIN` and `MAX`, the collection doesn't fit in the bounds
+    /// or the input is invalid.
+    pub fn deserialize<'de, T, C, D, const MIN: usize, const MAX: usize>(
+        deserializer: D,
+    ) -> Result<SizeRestricted<T, C, MIN, MAX>, D::Error>
+    where
+        C: LinearSizedCollection<T> + Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        let bounded = Bounded::<C>::deserialize(deserializer)?;
+        SizeRestricted::from_parts(bounded.data, bounded.min, bounded.max)
+            .map_err(|(e, _)| serde::de::Error::custom(e))
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test {
+    use alloc::{vec, vec::Vec};
+
+    use crate::SizeRestricted;
+
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Batch {
+        #[serde(with = "crate::serde_with::bounded")]
+        items: SizeRestricted<i32, Vec<i32>, 1, 3>,
+    }
+
+    #[test]
+    fn round_trip() {
+        let batch = Batch {
+            items: SizeRestricted::new(vec![1, 2]).unwrap(),
+        };
+        let json = serde_json::to_string(&batch).unwrap();
+        assert_eq!(json, r#"{"items":{"min":1,"max":3,"data":[1,2]}}"#);
+        assert_eq!(serde_json::from_str::<Batch>(&json).unwrap(), batch);
+    }
+
+    #[test]
+    fn bounds_mismatch() {
+        let json = r#"{"items":{"min":0,"max":3,"data":[1,2]}}"#;
+        let e = serde_json::from_str::<Batch>(json).unwrap_err();
+        assert!(e.to_string().starts_with("Bounds Mismatch"));
+
+        let json = r#"{"items":{"min":1,"max":3,"data":[1,2,3,4]}}"#;
+        let e = serde_json::from_str::<Batch>(json).unwrap_err();
+        assert!(e.to_string().starts_with("Too Large"));
+    }
+}
@@ -2,6 +2,29 @@
 //!
 //! The easiest way is to use the [`complete_test`] macro for the type you implement [`crate::LinearSizedCollection`] for
 
+/// Expands to the given items if srestricted is built with the `alloc` feature, used for tests of functions which
+/// need `alloc`.
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! alloc_only {
+    ($($item:item)*) => {
+        $($item)*
+    };
+}
+
+/// Expands to the given items if srestricted is built with the `alloc` feature, used for tests of functions which
+/// need `alloc`.
+#[cfg(not(feature = "alloc"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! alloc_only {
+    ($($item:item)*) => {};
+}
+
+#[doc(hidden)]
+pub use alloc_only;
+
 /// Test the coherence of a `LinearSizedCollection`.
 ///
 /// `$create` has to be an expression which creates the `LinearSizedCollection`.
@@ -64,12 +87,318 @@ macro_rules! linear_collection_test {
                 LinearSizedCollection::shrink_to(&mut collection, 2);
                 assert_eq!(LinearSizedCollection::len(&mut collection), 2);
             }
+
+            #[test]
+            fn swap_remove() {
+                let mut collection = $create;
+                for i in 0..5 {
+                    LinearSizedCollection::push(&mut collection, i);
+                }
+
+                assert_eq!(LinearSizedCollection::swap_remove(&mut collection, 5), None);
+                assert_eq!(
+                    LinearSizedCollection::swap_remove(&mut collection, 1),
+                    Some(1)
+                );
+                assert_eq!(LinearSizedCollection::len(&mut collection), 4);
+                assert_eq!(
+                    LinearSizedCollection::swap_remove(&mut collection, 3),
+                    Some(3)
+                );
+
+                assert_eq!(LinearSizedCollection::pop(&mut collection), Some(2));
+                assert_eq!(LinearSizedCollection::pop(&mut collection), Some(4));
+                assert_eq!(LinearSizedCollection::pop(&mut collection), Some(0));
+                assert_eq!(LinearSizedCollection::pop(&mut collection), None);
+            }
+
+            #[test]
+            fn count_retained() {
+                let mut collection = $create;
+                for i in 0..10 {
+                    LinearSizedCollection::push(&mut collection, i);
+                }
+
+                let retained =
+                    LinearSizedCollection::count_retained(&mut collection, |val| val % 3 == 0);
+                assert_eq!(retained, 4);
+                LinearSizedCollection::retain(&mut collection, |val| val % 3 == 0);
+                assert_eq!(LinearSizedCollection::len(&collection), retained);
+                assert_eq!(LinearSizedCollection::pop(&mut collection), Some(9));
+            }
+
+            #[test]
+            fn retain_mut() {
+                let mut collection = $create;
+                for i in 0..6 {
+                    LinearSizedCollection::push(&mut collection, i);
+                }
+
+                let mut visited = 0;
+                LinearSizedCollection::retain_mut(&mut collection, |val| {
+                    assert_eq!(*val, visited);
+                    visited += 1;
+                    *val *= 10;
+                    *val % 20 == 0
+                });
+                assert_eq!(LinearSizedCollection::len(&collection), 3);
+
+                assert_eq!(LinearSizedCollection::pop(&mut collection), Some(40));
+                assert_eq!(LinearSizedCollection::pop(&mut collection), Some(20));
+                assert_eq!(LinearSizedCollection::pop(&mut collection), Some(0));
+            }
+
+            $crate::test::alloc_only! {
+            #[test]
+            fn extract_if() {
+                let mut collection = $create;
+                for i in 0..6 {
+                    LinearSizedCollection::push(&mut collection, i);
+                }
+
+                let extracted =
+                    LinearSizedCollection::extract_if(&mut collection, |val| *val % 3 == 1);
+                assert_eq!(extracted, [1, 4]);
+                assert_eq!(LinearSizedCollection::len(&collection), 4);
+
+                assert_eq!(LinearSizedCollection::pop(&mut collection), Some(5));
+                assert_eq!(LinearSizedCollection::pop(&mut collection), Some(3));
+                assert_eq!(LinearSizedCollection::pop(&mut collection), Some(2));
+                assert_eq!(LinearSizedCollection::pop(&mut collection), Some(0));
+            }
+            }
         }
     };
 }
 
 pub use linear_collection_test;
 
+/// Test the coherence of a `LinearSizedCollection` which deduplicates its elements like a set, use this (or
+/// [`complete_set_test`]) instead of [`linear_collection_test`] which pushes equal elements.
+///
+/// `$create` has to be an expression which creates the `LinearSizedCollection`.
+/// `$name` has to be the name of the test module.
+#[macro_export]
+macro_rules! linear_set_test {
+    ($create:expr, $name:ident) => {
+        #[cfg(test)]
+        mod $name {
+            use $crate::LinearSizedCollection;
+            #[test]
+            fn pop_after_push() {
+                let mut collection = $create;
+                LinearSizedCollection::push(&mut collection, 10);
+
+                assert_eq!(LinearSizedCollection::pop(&mut collection), Some(10));
+                assert_eq!(LinearSizedCollection::pop(&mut collection), None);
+            }
+
+            #[test]
+            fn push_duplicate() {
+                let mut collection = $create;
+                assert!(LinearSizedCollection::grows_on_push(&collection, &10));
+                LinearSizedCollection::push(&mut collection, 10);
+                assert!(!LinearSizedCollection::grows_on_push(&collection, &10));
+                assert!(LinearSizedCollection::grows_on_push(&collection, &20));
+
+                LinearSizedCollection::push(&mut collection, 10);
+                assert_eq!(LinearSizedCollection::len(&collection), 1);
+            }
+
+            #[test]
+            fn multiple_resizes() {
+                let mut collection = $create;
+                let mut next = 0;
+                let mut fill = || {
+                    next += 1;
+                    next
+                };
+                LinearSizedCollection::extend_to_with(&mut collection, 10, &mut fill);
+                assert_eq!(LinearSizedCollection::len(&collection), 10);
+
+                LinearSizedCollection::shrink_to(&mut collection, 4);
+                assert_eq!(LinearSizedCollection::len(&collection), 4);
+
+                LinearSizedCollection::extend_to_with(&mut collection, 100, &mut fill);
+                assert_eq!(LinearSizedCollection::len(&collection), 100);
+
+                LinearSizedCollection::shrink_to(&mut collection, 2);
+                assert_eq!(LinearSizedCollection::len(&collection), 2);
+            }
+
+            #[test]
+            #[should_panic]
+            fn extend_duplicate() {
+                let mut collection = $create;
+                LinearSizedCollection::extend_to(&mut collection, 2, 0);
+            }
+
+            #[test]
+            fn pop_all() {
+                let mut collection = $create;
+                for i in 0..10 {
+                    LinearSizedCollection::push(&mut collection, i);
+                }
+
+                let mut popped = 0;
+                while let Some(val) = LinearSizedCollection::pop(&mut collection) {
+                    popped |= 1 << val;
+                }
+                assert_eq!(popped, 0b11_1111_1111);
+                assert!(LinearSizedCollection::is_empty(&collection));
+            }
+
+            #[test]
+            fn swap_remove() {
+                let mut collection = $create;
+                for i in 0..5 {
+                    LinearSizedCollection::push(&mut collection, i);
+                }
+
+                assert_eq!(LinearSizedCollection::swap_remove(&mut collection, 5), None);
+                let removed = LinearSizedCollection::swap_remove(&mut collection, 1).unwrap();
+                assert!((0..5).contains(&removed));
+                assert!(LinearSizedCollection::grows_on_push(&collection, &removed));
+                assert_eq!(LinearSizedCollection::len(&collection), 4);
+            }
+
+            #[test]
+            fn count_retained() {
+                let mut collection = $create;
+                for i in 0..10 {
+                    LinearSizedCollection::push(&mut collection, i);
+                }
+
+                let retained =
+                    LinearSizedCollection::count_retained(&mut collection, |val| val % 3 == 0);
+                assert_eq!(retained, 4);
+                LinearSizedCollection::retain(&mut collection, |val| val % 3 == 0);
+                assert_eq!(LinearSizedCollection::len(&collection), retained);
+                while let Some(val) = LinearSizedCollection::pop(&mut collection) {
+                    assert_eq!(val % 3, 0);
+                }
+            }
+
+            #[test]
+            fn retain_mut() {
+                let mut collection = $create;
+                for i in 0..6 {
+                    LinearSizedCollection::push(&mut collection, i);
+                }
+
+                LinearSizedCollection::retain_mut(&mut collection, |val| *val % 2 == 0);
+                assert_eq!(LinearSizedCollection::len(&collection), 3);
+                assert!(!LinearSizedCollection::grows_on_push(&collection, &4));
+                assert!(LinearSizedCollection::grows_on_push(&collection, &3));
+            }
+        }
+    };
+}
+
+pub use linear_set_test;
+
+/// Test the coherence of a `LinearSizedDeque`, this should be used in addition to [`complete_test`].
+///
+/// `$create` has to be an expression which creates the `LinearSizedDeque`.
+/// `$name` has to be the name of the test module.
+#[macro_export]
+macro_rules! linear_deque_test {
+    ($create:expr, $name:ident) => {
+        #[cfg(test)]
+        mod $name {
+            use $crate::{LinearSizedCollection, LinearSizedDeque};
+            #[test]
+            fn pop_front_after_push_front() {
+                let mut collection = $create;
+                LinearSizedDeque::push_front(&mut collection, 10);
+                LinearSizedDeque::push_front(&mut collection, 20);
+
+                assert_eq!(LinearSizedDeque::pop_front(&mut collection), Some(20));
+                assert_eq!(LinearSizedDeque::pop_front(&mut collection), Some(10));
+                assert_eq!(LinearSizedDeque::pop_front(&mut collection), None);
+            }
+
+            #[test]
+            fn front_and_back() {
+                let mut collection = $create;
+                LinearSizedCollection::push(&mut collection, 10);
+                LinearSizedDeque::push_front(&mut collection, 20);
+                assert_eq!(LinearSizedCollection::len(&collection), 2);
+
+                assert_eq!(LinearSizedCollection::pop(&mut collection), Some(10));
+                assert_eq!(LinearSizedDeque::pop_front(&mut collection), Some(20));
+            }
+
+            #[test]
+            fn truncate_front() {
+                let mut collection = $create;
+                for i in 0..5 {
+                    LinearSizedCollection::push(&mut collection, i);
+                }
+
+                LinearSizedDeque::truncate_front(&mut collection, 2);
+                assert_eq!(LinearSizedCollection::len(&collection), 2);
+                assert_eq!(LinearSizedDeque::pop_front(&mut collection), Some(3));
+
+                LinearSizedDeque::truncate_front(&mut collection, 5);
+                assert_eq!(LinearSizedDeque::pop_front(&mut collection), Some(4));
+            }
+        }
+    };
+}
+
+pub use linear_deque_test;
+
+/// Test the coherence of an `Iterable` collection, this should be used in addition to [`complete_test`].
+///
+/// `$create` has to be an expression which creates the `Iterable` collection.
+/// `$name` has to be the name of the test module.
+#[macro_export]
+macro_rules! iterable_test {
+    ($create:expr, $name:ident) => {
+        #[cfg(test)]
+        mod $name {
+            use $crate::{Iterable, LinearSizedCollection};
+            #[test]
+            fn iter_front_to_back() {
+                let mut collection = $create;
+                for i in 0..5 {
+                    LinearSizedCollection::push(&mut collection, i);
+                }
+
+                let mut iter = Iterable::iter(&collection);
+                for i in 0..5 {
+                    assert_eq!(iter.next(), Some(&i));
+                }
+                assert_eq!(iter.next(), None);
+            }
+
+            #[test]
+            fn iter_len() {
+                let mut collection = $create;
+                LinearSizedCollection::extend_to(&mut collection, 10, 0);
+                assert_eq!(Iterable::iter(&collection).count(), 10);
+            }
+
+            #[test]
+            fn iter_back_to_front() {
+                let mut collection = $create;
+                for i in 0..5 {
+                    LinearSizedCollection::push(&mut collection, i);
+                }
+
+                let mut iter = Iterable::iter(&collection).rev();
+                for i in (0..5).rev() {
+                    assert_eq!(iter.next(), Some(&i));
+                }
+                assert_eq!(iter.next(), None);
+            }
+        }
+    };
+}
+
+pub use iterable_test;
+
 /// Tests for [`SizeRestricted`] collection with the underlying collection being the tested type.
 #[macro_export]
 macro_rules! size_restricted_collection {
@@ -151,3 +480,27 @@ macro_rules! complete_test {
 }
 
 pub use complete_test;
+
+/// Creates a complete test suite for a set like type which deduplicates its elements, like [`complete_test`] but with
+/// [`linear_set_test`] instead of [`linear_collection_test`].
+///
+/// $name should be the name of the test module and $create has to be an expression which creates an instance of the type to be tested.
+#[macro_export]
+macro_rules! complete_set_test {
+    ($create:expr, $name:ident) => {
+        #[cfg(test)]
+        mod $name {
+            #[cfg(test)]
+            mod linear_set_test {
+                $crate::test::linear_set_test!($create, $name);
+            }
+
+            #[cfg(test)]
+            mod size_restricted_collection {
+                $crate::test::size_restricted_collection!($create, $name);
+            }
+        }
+    };
+}
+
+pub use complete_set_test;